@@ -0,0 +1,39 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use validity::{bench_validator, Validate};
+
+#[derive(Debug)]
+struct PhoneNumber(String);
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum InvalidPhoneNumber {
+    NonDigit,
+    WrongLength,
+}
+
+impl Validate for PhoneNumber {
+    type Context<'a> = ();
+    type Error = InvalidPhoneNumber;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        if self.0.len() != 11 {
+            return Err(InvalidPhoneNumber::WrongLength);
+        }
+
+        if self.0.chars().any(|c| !c.is_ascii_digit()) {
+            return Err(InvalidPhoneNumber::NonDigit);
+        }
+
+        Ok(())
+    }
+}
+
+fn phone_number(c: &mut Criterion) {
+    let passing = ["01234567890", "07700900123", "02079460000"].map(|s| PhoneNumber(s.into()));
+    let failing = ["0123456789", "0123456789a", "+4477009001"].map(|s| PhoneNumber(s.into()));
+
+    bench_validator(c, "phone_number", &passing, &failing, ());
+}
+
+criterion_group!(benches, phone_number);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+use std::{
+    boxed::Box,
+    fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+};
+
+use actix_web::{
+    dev::Payload,
+    error::InternalError,
+    http::StatusCode,
+    web::{Form, Json},
+    FromRequest, HttpRequest,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{Valid, Validate};
+
+type ExtractFuture<T> = Pin<Box<dyn Future<Output = Result<T, actix_web::Error>>>>;
+
+/// An actix-web extractor which deserializes a JSON body, then validates it
+///
+/// This is only available with the `actix` feature enabled.
+///
+/// The body is deserialized with [`Json`] (so any [`JsonConfig`](actix_web::web::JsonConfig) is
+/// respected), then validated. If validation fails, the request is rejected with
+/// `400 Bad Request`, and the validation error's `Display` output as the body. actix logs its
+/// `Debug` output, so a [`Redacted`](crate::Redacted) error keeps internal detail out of the
+/// response while still logging it:
+/// ```
+/// # use validity::*;
+/// # use actix_web::{web, App};
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Signup {
+///   username: String,
+/// }
+///
+/// impl Validate for Signup {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.username.is_empty() {
+///       true => Err("username must not be empty"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// async fn signup(ValidatedJson(signup): ValidatedJson<Signup>) -> String {
+///   format!("welcome, {}", signup.into_inner().username)
+/// }
+///
+/// let app = App::new().route("/signup", web::post().to(signup));
+/// ```
+#[derive(Debug)]
+pub struct ValidatedJson<T>(pub Valid<T>);
+
+impl<T> ValidatedJson<T> {
+    /// Get the validated value
+    pub fn into_inner(self) -> Valid<T> {
+        self.0
+    }
+}
+
+impl<T> FromRequest for ValidatedJson<T>
+where
+    T: DeserializeOwned + for<'a> Validate<Context<'a> = ()> + 'static,
+    T::Error: Debug + Display + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = ExtractFuture<Self>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let json = Json::<T>::from_request(req, payload);
+        Box::pin(async move { validate_extracted(json.await?.into_inner()).map(Self) })
+    }
+}
+
+/// An actix-web extractor which deserializes a URL-encoded form body, then validates it
+///
+/// This is only available with the `actix` feature enabled.
+///
+/// This behaves like [`ValidatedJson`], but deserializes the body with [`Form`].
+#[derive(Debug)]
+pub struct ValidatedForm<T>(pub Valid<T>);
+
+impl<T> ValidatedForm<T> {
+    /// Get the validated value
+    pub fn into_inner(self) -> Valid<T> {
+        self.0
+    }
+}
+
+impl<T> FromRequest for ValidatedForm<T>
+where
+    T: DeserializeOwned + for<'a> Validate<Context<'a> = ()> + 'static,
+    T::Error: Debug + Display + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = ExtractFuture<Self>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let form = Form::<T>::from_request(req, payload);
+        Box::pin(async move { validate_extracted(form.await?.into_inner()).map(Self) })
+    }
+}
+
+fn validate_extracted<T>(value: T) -> Result<Valid<T>, actix_web::Error>
+where
+    T: for<'a> Validate<Context<'a> = ()>,
+    T::Error: Debug + Display + 'static,
+{
+    value
+        .validate()
+        .map_err(|failure| InternalError::new(failure.error, StatusCode::BAD_REQUEST).into())
+}
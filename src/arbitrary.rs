@@ -0,0 +1,154 @@
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+use crate::{Valid, Validate};
+
+/// Create a strategy which generates `Valid<T>` from a strategy which generates `T`
+///
+/// Generated values which fail validation are rejected (in the same way as
+/// [`Strategy::prop_filter`]), so if valid values are rare, you should write a strategy that
+/// generates them more directly.
+///
+/// Shrinking is also filtered through validation: every value produced while shrinking is valid,
+/// so a failing test case is always reported with a value that could actually exist inside a
+/// `Valid<T>`:
+/// ```
+/// # use validity::*;
+/// # use proptest::{strategy::{Strategy, ValueTree}, test_runner::TestRunner};
+/// #[derive(Debug, Clone)]
+/// struct Even(u32);
+///
+/// impl Validate for Even {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 % 2 {
+///       0 => Ok(()),
+///       _ => Err(()),
+///     }
+///   }
+/// }
+///
+/// let strategy = valid((0..1000u32).prop_map(Even));
+/// let mut runner = TestRunner::deterministic();
+///
+/// for _ in 0..100 {
+///   let mut tree = strategy.new_tree(&mut runner).unwrap();
+///   assert_eq!(tree.current().into_inner().0 % 2, 0);
+///
+///   while tree.simplify() {
+///     assert_eq!(tree.current().into_inner().0 % 2, 0);
+///     if tree.current().into_inner().0 % 3 == 0 {
+///       tree.complicate();
+///       assert_eq!(tree.current().into_inner().0 % 2, 0);
+///     }
+///   }
+/// }
+/// ```
+pub fn valid<S>(strategy: S) -> ValidStrategy<S>
+where
+    S: Strategy,
+    S::Value: for<'a> Validate<Context<'a> = ()>,
+{
+    ValidStrategy { inner: strategy }
+}
+
+/// A [`Strategy`] which generates `Valid<T>`
+///
+/// See [`valid`] for more details
+#[derive(Debug, Clone)]
+#[must_use = "strategies do nothing unless used"]
+pub struct ValidStrategy<S> {
+    inner: S,
+}
+
+impl<S> Strategy for ValidStrategy<S>
+where
+    S: Strategy,
+    S::Value: for<'a> Validate<Context<'a> = ()>,
+{
+    type Tree = ValidValueTree<S::Tree>;
+    type Value = Valid<S::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        loop {
+            let inner = self.inner.new_tree(runner)?;
+            if inner.current().is_valid(()).is_ok() {
+                return Ok(ValidValueTree { inner });
+            }
+
+            runner.reject_local("value failed validation")?;
+        }
+    }
+}
+
+/// The [`ValueTree`] for [`ValidStrategy`]
+///
+/// The current value of the inner tree is always valid
+#[derive(Debug, Clone)]
+pub struct ValidValueTree<T> {
+    inner: T,
+}
+
+impl<T> ValidValueTree<T>
+where
+    T: ValueTree,
+    T::Value: for<'a> Validate<Context<'a> = ()>,
+{
+    fn ensure_valid(&mut self) {
+        while self.inner.current().is_valid(()).is_err() {
+            if !self.inner.complicate() {
+                panic!("unable to complicate shrunk value back into a valid value");
+            }
+        }
+    }
+}
+
+impl<T> ValueTree for ValidValueTree<T>
+where
+    T: ValueTree,
+    T::Value: for<'a> Validate<Context<'a> = ()>,
+{
+    type Value = Valid<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        Valid(self.inner.current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.inner.simplify() {
+            self.ensure_valid();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.inner.complicate() {
+            self.ensure_valid();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Generates arbitrary values of `T`, rejecting any that fail validation
+///
+/// See [`valid`] for more details
+impl<T> Arbitrary for Valid<T>
+where
+    T: Arbitrary + for<'a> Validate<Context<'a> = ()>,
+{
+    type Parameters = T::Parameters;
+    type Strategy = ValidStrategy<T::Strategy>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        valid(any_with::<T>(args))
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+
+use crate::{Failure, Valid, Validate};
+
+/// A record of the checks performed while validating a value
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ValidationAudit {
+    checks: Vec<&'static str>,
+}
+
+impl ValidationAudit {
+    /// Create a new, empty audit record
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that a check was performed
+    pub fn record(&mut self, check: &'static str) {
+        self.checks.push(check);
+    }
+
+    /// The checks that were performed, in the order they were recorded
+    pub fn checks(&self) -> &[&'static str] {
+        &self.checks
+    }
+}
+
+/// A type whose validation can report which checks it performed
+///
+/// This is useful for audit trails, where it isn't enough to know that a value was valid, but also
+/// which rules it was checked against:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Transfer {
+///   amount: u64,
+///   currency: &'static str,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidTransfer {
+///   Zero,
+///   TooLarge,
+///   UnknownCurrency,
+/// }
+///
+/// impl Validate for Transfer {
+///   type Context<'a> = ();
+///   type Error = InvalidTransfer;
+///
+///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     self.is_valid_audited(ctx).map(|_| ())
+///   }
+/// }
+///
+/// impl AuditedValidate for Transfer {
+///   fn is_valid_audited(&self, _ctx: Self::Context<'_>) -> Result<ValidationAudit, Self::Error> {
+///     let mut audit = ValidationAudit::new();
+///
+///     audit.record("amount is non-zero");
+///     if self.amount == 0 {
+///       return Err(InvalidTransfer::Zero);
+///     }
+///
+///     audit.record("amount is within limit");
+///     if self.amount > 10_000 {
+///       return Err(InvalidTransfer::TooLarge);
+///     }
+///
+///     audit.record("currency is supported");
+///     if !["GBP", "EUR"].contains(&self.currency) {
+///       return Err(InvalidTransfer::UnknownCurrency);
+///     }
+///
+///     Ok(audit)
+///   }
+/// }
+///
+/// let transfer = Transfer { amount: 500, currency: "GBP" };
+/// let (transfer, audit) = transfer.validate_audited(()).unwrap();
+///
+/// assert_eq!(transfer.amount, 500);
+/// assert_eq!(
+///   audit.checks(),
+///   ["amount is non-zero", "amount is within limit", "currency is supported"],
+/// );
+///
+/// let failure = Transfer { amount: 0, currency: "GBP" }.validate_audited(()).unwrap_err();
+/// assert_eq!(failure.error, InvalidTransfer::Zero);
+/// ```
+///
+/// The audited validation must accept exactly the same values as [`Validate::is_valid`]. The
+/// simplest way to guarantee this is to implement `is_valid` in terms of `is_valid_audited`, as
+/// above.
+pub trait AuditedValidate: Validate {
+    /// Perform the validation on this object, recording each check that was performed
+    fn is_valid_audited(&self, ctx: Self::Context<'_>) -> Result<ValidationAudit, Self::Error>;
+
+    /// Validate with the given context, returning the audit record along with the valid value
+    fn validate_audited(
+        self,
+        ctx: Self::Context<'_>,
+    ) -> Result<(Valid<Self>, ValidationAudit), Failure<Self>>
+    where
+        Self: Sized,
+    {
+        match self.is_valid_audited(ctx) {
+            Ok(audit) => Ok((Valid(self), audit)),
+            Err(error) => Err(Failure { value: self, error }),
+        }
+    }
+}
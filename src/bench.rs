@@ -0,0 +1,84 @@
+use core::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+
+use crate::Validate;
+
+/// Benchmark a validator against sets of passing and failing inputs
+///
+/// This is only available with the `bench-support` feature enabled.
+///
+/// This creates a benchmark group called `name`, with one benchmark for the passing inputs and
+/// one for the failing inputs. Throughput is reported per input, so criterion reports the number
+/// of validations per second. Each input is checked first, and this panics if an input is in the
+/// wrong set, so that a broken validator doesn't produce misleading numbers.
+///
+/// This is intended to be called from a `criterion` benchmark:
+/// ```no_run
+/// # use validity::*;
+/// # use criterion::{criterion_group, criterion_main, Criterion};
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(()),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// fn port(c: &mut Criterion) {
+///   bench_validator(c, "port", &[Port(80), Port(443)], &[Port(0)], ());
+/// }
+///
+/// criterion_group!(benches, port);
+/// criterion_main!(benches);
+/// ```
+pub fn bench_validator<T>(
+    c: &mut Criterion,
+    name: &str,
+    passing: &[T],
+    failing: &[T],
+    ctx: T::Context<'_>,
+) where
+    T: Validate,
+    for<'a> T::Context<'a>: Clone,
+{
+    for (index, input) in passing.iter().enumerate() {
+        assert!(
+            input.is_valid(ctx.clone()).is_ok(),
+            "passing input {index} failed validation"
+        );
+    }
+
+    for (index, input) in failing.iter().enumerate() {
+        assert!(
+            input.is_valid(ctx.clone()).is_err(),
+            "failing input {index} passed validation"
+        );
+    }
+
+    let mut group = c.benchmark_group(name);
+
+    for (label, inputs) in [("passing", passing), ("failing", failing)] {
+        if inputs.is_empty() {
+            continue;
+        }
+
+        group.throughput(Throughput::Elements(inputs.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), inputs, |b, inputs| {
+            b.iter(|| {
+                for input in inputs {
+                    let _ = black_box(black_box(input).is_valid(ctx.clone()));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
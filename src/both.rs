@@ -0,0 +1,93 @@
+/// A context made of two independent components
+///
+/// When a validator needs two unrelated things (for example, a database handle and a clock),
+/// `Both` saves defining a struct for each combination. Each component can borrow for a different
+/// lifetime, as long as both outlive the context's lifetime:
+/// ```
+/// # use validity::*;
+/// # use std::collections::HashSet;
+/// struct Db {
+///   taken: HashSet<&'static str>,
+/// }
+///
+/// struct Clock {
+///   now: u64,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Reservation {
+///   name: &'static str,
+///   starts_at: u64,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidReservation {
+///   NameTaken,
+///   InThePast,
+/// }
+///
+/// impl Validate for Reservation {
+///   type Context<'a> = Both<&'a Db, &'a Clock>;
+///   type Error = InvalidReservation;
+///
+///   fn is_valid(&self, Both(db, clock): Self::Context<'_>) -> Result<(), Self::Error> {
+///     if db.taken.contains(self.name) {
+///       return Err(InvalidReservation::NameTaken);
+///     }
+///
+///     if self.starts_at < clock.now {
+///       return Err(InvalidReservation::InThePast);
+///     }
+///
+///     Ok(())
+///   }
+/// }
+///
+/// let db = Db { taken: HashSet::from(["dinner"]) };
+///
+/// // the clock is borrowed for a shorter lifetime than the database
+/// for (now, expected) in [(50, Ok(())), (150, Err(InvalidReservation::InThePast))] {
+///   let clock = Clock { now };
+///   let result = Reservation { name: "lunch", starts_at: 100 }
+///     .validate_with(Both(&db, &clock))
+///     .map(|_| ())
+///     .map_err(|failure| failure.error);
+///   assert_eq!(result, expected);
+/// }
+///
+/// let clock = Clock { now: 0 };
+/// let failure = Reservation { name: "dinner", starts_at: 100 }
+///   .validate_with(Both(&db, &clock))
+///   .unwrap_err();
+/// assert_eq!(failure.error, InvalidReservation::NameTaken);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Both<A, B>(pub A, pub B);
+
+impl<A, B> Both<A, B> {
+    /// Combine two contexts
+    pub fn new(first: A, second: B) -> Self {
+        Self(first, second)
+    }
+
+    /// The first component
+    pub fn first(&self) -> &A {
+        &self.0
+    }
+
+    /// The second component
+    pub fn second(&self) -> &B {
+        &self.1
+    }
+
+    /// Split this context into its components
+    pub fn into_parts(self) -> (A, B) {
+        (self.0, self.1)
+    }
+}
+
+impl<A, B> From<(A, B)> for Both<A, B> {
+    fn from((first, second): (A, B)) -> Self {
+        Self(first, second)
+    }
+}
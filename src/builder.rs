@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+
+use crate::{Failure, Valid, Validate};
+
+/// A builder which produces a `Valid<T>`, validating only once every field has been set
+///
+/// Implementors provide [`ValidBuilder::assemble`], which turns the builder's (possibly
+/// incomplete) state into a `Target`. The builder itself never claims to be valid, so the only way
+/// to get a `Valid<Target>` out of it is [`ValidBuilder::build`], which checks both that every
+/// required field was set, and that the assembled value is valid:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Server {
+///   host: String,
+///   port: u16,
+///   workers: usize,
+/// }
+///
+/// impl Validate for Server {
+///   type Context<'a> = ();
+///   type Error = Vec<&'static str>;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     let mut errors = vec![];
+///     if self.host.is_empty() {
+///       errors.push("host must not be empty");
+///     }
+///     if self.port == 0 {
+///       errors.push("port must not be zero");
+///     }
+///     match errors.is_empty() {
+///       true => Ok(()),
+///       false => Err(errors),
+///     }
+///   }
+/// }
+///
+/// #[derive(Default)]
+/// struct ServerBuilder {
+///   host: Option<String>,
+///   port: Option<u16>,
+///   workers: Option<usize>,
+/// }
+///
+/// impl ServerBuilder {
+///   fn host(mut self, host: &str) -> Self {
+///     self.host = Some(host.to_string());
+///     self
+///   }
+///
+///   fn port(mut self, port: u16) -> Self {
+///     self.port = Some(port);
+///     self
+///   }
+/// }
+///
+/// impl ValidBuilder for ServerBuilder {
+///   type Target = Server;
+///
+///   fn assemble(self) -> Result<Server, MissingFields> {
+///     let mut missing = MissingFields::new();
+///     let host = missing.require("host", self.host);
+///     let port = missing.require("port", self.port);
+///     let workers = self.workers.unwrap_or(4);
+///
+///     match (host, port) {
+///       (Some(host), Some(port)) => Ok(Server { host, port, workers }),
+///       _ => Err(missing),
+///     }
+///   }
+/// }
+///
+/// let server = ServerBuilder::default().host("localhost").port(8080).build(()).unwrap();
+/// assert_eq!(server.workers, 4);
+///
+/// let error = ServerBuilder::default().port(8080).build(()).unwrap_err();
+/// let BuildError::Missing(missing) = error else { panic!() };
+/// assert_eq!(missing.fields(), ["host"]);
+///
+/// let error = ServerBuilder::default().host("").port(0).build(()).unwrap_err();
+/// let BuildError::Invalid(failure) = error else { panic!() };
+/// assert_eq!(failure.error, ["host must not be empty", "port must not be zero"]);
+/// ```
+pub trait ValidBuilder: Sized {
+    /// The type that this builder produces
+    type Target: Validate;
+
+    /// Convert this builder into a `Target`, without validating it
+    ///
+    /// If any required fields are missing, they should be returned as a [`MissingFields`]
+    fn assemble(self) -> Result<Self::Target, MissingFields>;
+
+    /// Assemble the `Target`, and validate it with the given context
+    fn build(
+        self,
+        ctx: <Self::Target as Validate>::Context<'_>,
+    ) -> Result<Valid<Self::Target>, BuildError<Self::Target>> {
+        self.assemble()
+            .map_err(BuildError::Missing)?
+            .validate_with(ctx)
+            .map_err(BuildError::Invalid)
+    }
+}
+
+/// The names of required fields which were not set on a [`ValidBuilder`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MissingFields(Vec<&'static str>);
+
+impl MissingFields {
+    /// Create a new, empty list of missing fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `name` as missing if `value` is `None`, and return `value`
+    pub fn require<V>(&mut self, name: &'static str, value: Option<V>) -> Option<V> {
+        if value.is_none() {
+            self.0.push(name);
+        }
+
+        value
+    }
+
+    /// The names of the missing fields, in the order they were recorded
+    pub fn fields(&self) -> &[&'static str] {
+        &self.0
+    }
+
+    /// Returns `true` if no fields are missing
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The error returned by [`ValidBuilder::build`]
+pub enum BuildError<T: Validate> {
+    /// Some required fields were not set
+    Missing(MissingFields),
+    /// Every field was set, but the assembled value failed validation
+    Invalid(Failure<T>),
+}
+
+impl<T> Debug for BuildError<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Missing(missing) => f.debug_tuple("Missing").field(missing).finish(),
+            Self::Invalid(failure) => f.debug_tuple("Invalid").field(failure).finish(),
+        }
+    }
+}
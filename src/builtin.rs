@@ -0,0 +1,168 @@
+//! Ready-made validated types for common cases
+//!
+//! This module is only available with the `std-validators` feature enabled.
+//!
+//! These cover common constraints, so they don't need to be reimplemented for every project. Each
+//! type is a newtype with a public field, and is validated like any other type:
+//! ```
+//! # use validity::{*, builtin::*};
+//! let name = NonEmptyString("alice".to_string()).validate().unwrap();
+//! let port = Port(8080).validate().unwrap();
+//! let ratio = Percentage(12.5).validate().unwrap();
+//! let level = BoundedI64::<1, 10>(3).validate().unwrap();
+//!
+//! assert_eq!(name.0, "alice");
+//! assert_eq!(port.0, 8080);
+//! assert_eq!(ratio.0, 12.5);
+//! assert_eq!(level.0, 3);
+//! ```
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use crate::Validate;
+
+/// A `String` which is not empty
+///
+/// ```
+/// # use validity::{*, builtin::*};
+/// assert!(NonEmptyString("a".to_string()).validate().is_ok());
+/// assert_eq!(NonEmptyString(String::new()).validate().unwrap_err().error, EmptyString);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(pub String);
+
+impl Validate for NonEmptyString {
+    type Context<'a> = ();
+    type Error = EmptyString;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.0.is_empty() {
+            true => Err(EmptyString),
+            false => Ok(()),
+        }
+    }
+}
+
+/// An `i64` in the range `MIN..=MAX`
+///
+/// ```
+/// # use validity::{*, builtin::*};
+/// type Level = BoundedI64<1, 10>;
+///
+/// assert!(Level::new(1).validate().is_ok());
+/// assert!(Level::new(10).validate().is_ok());
+///
+/// let failure = Level::new(0).validate().unwrap_err();
+/// assert_eq!(failure.error, OutOfRange { value: 0, min: 1, max: 10 });
+/// assert!(Level::new(11).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedI64<const MIN: i64, const MAX: i64>(pub i64);
+
+impl<const MIN: i64, const MAX: i64> BoundedI64<MIN, MAX> {
+    /// Create a new, unvalidated `BoundedI64`
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> Validate for BoundedI64<MIN, MAX> {
+    type Context<'a> = ();
+    type Error = OutOfRange<i64>;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        OutOfRange::check(self.0, MIN, MAX)
+    }
+}
+
+/// A TCP/UDP port number, which must be non-zero
+///
+/// ```
+/// # use validity::{*, builtin::*};
+/// assert!(Port(1).validate().is_ok());
+/// assert!(Port(65535).validate().is_ok());
+///
+/// let failure = Port(0).validate().unwrap_err();
+/// assert_eq!(failure.error, OutOfRange { value: 0, min: 1, max: 65535 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Port(pub u16);
+
+impl Validate for Port {
+    type Context<'a> = ();
+    type Error = OutOfRange<u16>;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        OutOfRange::check(self.0, 1, u16::MAX)
+    }
+}
+
+/// A percentage in the range `0.0..=100.0`
+///
+/// `NaN` is never valid.
+/// ```
+/// # use validity::{*, builtin::*};
+/// assert!(Percentage(0.0).validate().is_ok());
+/// assert!(Percentage(100.0).validate().is_ok());
+///
+/// assert!(Percentage(-0.1).validate().is_err());
+/// assert!(Percentage(100.1).validate().is_err());
+/// assert!(Percentage(f64::NAN).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Percentage(pub f64);
+
+impl Validate for Percentage {
+    type Context<'a> = ();
+    type Error = OutOfRange<f64>;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        OutOfRange::check(self.0, 0.0, 100.0)
+    }
+}
+
+/// The error for an empty [`NonEmptyString`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyString;
+
+impl Display for EmptyString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("string must not be empty")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyString {}
+
+/// The error for a value outside the range `min..=max`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange<T> {
+    /// The value which was out of range
+    pub value: T,
+    /// The smallest valid value
+    pub min: T,
+    /// The largest valid value
+    pub max: T,
+}
+
+impl<T: PartialOrd> OutOfRange<T> {
+    fn check(value: T, min: T, max: T) -> Result<(), Self> {
+        match min <= value && value <= max {
+            true => Ok(()),
+            false => Err(Self { value, min, max }),
+        }
+    }
+}
+
+impl<T: Display> Display for OutOfRange<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} is out of range (expected {} to {})",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display + core::fmt::Debug> std::error::Error for OutOfRange<T> {}
@@ -0,0 +1,76 @@
+use core::fmt::{Debug, Formatter};
+
+use bytemuck::{AnyBitPattern, PodCastError};
+
+use crate::{Failure, Valid, Validate};
+
+/// Read a `T` from a byte slice, and then validate it
+///
+/// Since `Valid<T>` is not `repr(transparent)`, it is not sound to cast bytes directly into a
+/// `Valid<T>`. Instead, this function reads a `T` out of `bytes` (which can have any alignment),
+/// and then validates it as normal.
+///
+/// `bytes` must be exactly `size_of::<T>()` bytes long:
+/// ```
+/// # use validity::*;
+/// # use bytemuck::{Pod, Zeroable};
+/// #[repr(C, packed)]
+/// #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+/// struct Header {
+///   version: u8,
+///   len: u16,
+/// }
+///
+/// impl Validate for Header {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.version {
+///       1 | 2 => Ok(()),
+///       _ => Err("unknown version"),
+///     }
+///   }
+/// }
+///
+/// let header = validate_from_bytes::<Header>(&[2, 0x10, 0x00]).unwrap();
+/// assert_eq!({ header.len }, u16::from_le_bytes([0x10, 0x00]));
+///
+/// let Err(FromBytesError::Invalid(failure)) = validate_from_bytes::<Header>(&[7, 0x10, 0x00]) else {
+///   panic!("expected validation to fail");
+/// };
+/// assert_eq!(failure.error, "unknown version");
+///
+/// let Err(FromBytesError::Cast(_)) = validate_from_bytes::<Header>(&[2, 0x10]) else {
+///   panic!("expected cast to fail");
+/// };
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_from_bytes<T>(bytes: &[u8]) -> Result<Valid<T>, FromBytesError<T>>
+where
+    T: AnyBitPattern + for<'a> Validate<Context<'a> = ()>,
+{
+    let value = bytemuck::try_pod_read_unaligned::<T>(bytes).map_err(FromBytesError::Cast)?;
+    value.validate().map_err(FromBytesError::Invalid)
+}
+
+/// The error returned by [`validate_from_bytes`]
+pub enum FromBytesError<T: Validate> {
+    /// The bytes could not be read as a `T` (usually because the length was wrong)
+    Cast(PodCastError),
+    /// The bytes were read as a `T`, but the value failed validation
+    Invalid(Failure<T>),
+}
+
+impl<T> Debug for FromBytesError<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Cast(error) => f.debug_tuple("Cast").field(error).finish(),
+            Self::Invalid(failure) => f.debug_tuple("Invalid").field(failure).finish(),
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use core::{
+    any::TypeId,
+    hash::{BuildHasher, Hash},
+};
+use std::{
+    collections::{hash_map::RandomState, BTreeMap, HashMap},
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use crate::{Failure, Valid, Validate};
+
+/// The capacity of the global cache, unless changed with [`ValidationCache::set_capacity`]
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// A bounded cache of values which are known to be valid
+///
+/// Values are identified by their type and a hash of their contents, so only the hash is stored,
+/// not the value itself. When the cache is full, the least recently used entry is evicted.
+///
+/// Only successful validations are cached, so an invalid value is always re-validated (and its
+/// error is always available).
+///
+/// Since only context-free validation can be cached, this is only usable for types whose context
+/// is `()`:
+/// ```
+/// # use validity::*;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// static CALLS: AtomicUsize = AtomicUsize::new(0);
+///
+/// #[derive(Debug, Hash)]
+/// struct Document(String);
+///
+/// impl Validate for Document {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     CALLS.fetch_add(1, Ordering::SeqCst);
+///     match self.0.is_empty() {
+///       true => Err(()),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let mut cache = ValidationCache::new(2);
+///
+/// // the first validation is a miss
+/// cache.validate(Document("a".to_string())).unwrap();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+///
+/// // equal values are a hit
+/// cache.validate(Document("a".to_string())).unwrap();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+///
+/// // fill the cache, which evicts "a"
+/// cache.validate(Document("b".to_string())).unwrap();
+/// cache.validate(Document("c".to_string())).unwrap();
+/// assert_eq!(cache.len(), 2);
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+///
+/// cache.validate(Document("a".to_string())).unwrap();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 4);
+///
+/// // using "c" makes "a" the least recently used, so "d" evicts it
+/// cache.validate(Document("c".to_string())).unwrap();
+/// cache.validate(Document("d".to_string())).unwrap();
+/// cache.validate(Document("c".to_string())).unwrap();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 5);
+///
+/// cache.validate(Document("a".to_string())).unwrap();
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 6);
+///
+/// // invalid values are never cached
+/// assert!(cache.validate(Document(String::new())).is_err());
+/// assert!(cache.validate(Document(String::new())).is_err());
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 8);
+/// ```
+///
+/// Note, two values with the same type and hash are considered identical, so a hash collision
+/// between a valid and an invalid value would allow the invalid value to be treated as valid. The
+/// hasher is randomly seeded to make collisions hard to construct deliberately, but this cache
+/// should not be used where that risk is unacceptable.
+///
+/// For the same reason, a type's `Hash` impl must cover all of the state its `is_valid` reads. If
+/// it doesn't (for example, a custom impl which only hashes an id, or a value with interior
+/// mutability), a value can share a cache entry with an earlier valid value while being invalid
+/// itself, and it will be returned as `Valid` without being checked.
+#[derive(Debug)]
+pub struct ValidationCache {
+    capacity: usize,
+    hasher: RandomState,
+    tick: u64,
+    // each entry's last use, and the reverse, so the least recently used entry is the first
+    entries: HashMap<(TypeId, u64), u64>,
+    by_last_used: BTreeMap<u64, (TypeId, u64)>,
+}
+
+impl ValidationCache {
+    /// Create a new, empty cache which holds at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            hasher: RandomState::new(),
+            tick: 0,
+            entries: HashMap::new(),
+            by_last_used: BTreeMap::new(),
+        }
+    }
+
+    /// The process-wide cache used by [`Validate::validate_cached_global`]
+    ///
+    /// It is created with a capacity of [`DEFAULT_CACHE_CAPACITY`]
+    pub fn global() -> &'static Mutex<ValidationCache> {
+        static GLOBAL: OnceLock<Mutex<ValidationCache>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Mutex::new(ValidationCache::new(DEFAULT_CACHE_CAPACITY)))
+    }
+
+    /// The maximum number of entries this cache can hold
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity of this cache, evicting entries if it is now over capacity
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > self.capacity {
+            self.evict();
+        }
+    }
+
+    /// The number of entries currently in the cache
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether this cache has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all entries from this cache
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.by_last_used.clear();
+    }
+
+    /// Validate a value, skipping validation if an identical value is known to be valid
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn validate<T>(&mut self, value: T) -> Result<Valid<T>, Failure<T>>
+    where
+        T: for<'a> Validate<Context<'a> = ()> + Hash + 'static,
+    {
+        let key = self.key(&value);
+        if self.hit(key) {
+            return Ok(Valid(value));
+        }
+
+        let valid = value.validate()?;
+        self.insert(key);
+        Ok(valid)
+    }
+
+    /// Validate a value, skipping validation if a value with the same [`ValidDiscriminant`] is
+    /// known to be valid
+    ///
+    /// Unlike [`ValidationCache::validate`], the value itself isn't hashed, which is cheaper for
+    /// large values whose validity only depends on their variant:
+    /// ```
+    /// # use validity::*;
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// static CALLS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(Debug)]
+    /// enum Message {
+    ///   Ping,
+    ///   Upload(Vec<u8>),
+    ///   Legacy(Vec<u8>),
+    /// }
+    ///
+    /// impl Validate for Message {
+    ///   type Context<'a> = ();
+    ///   type Error = &'static str;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     CALLS.fetch_add(1, Ordering::SeqCst);
+    ///     match self {
+    ///       Message::Legacy(_) => Err("legacy messages are not supported"),
+    ///       _ => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// impl ValidDiscriminant for Message {
+    ///   fn valid_discriminant(&self) -> u64 {
+    ///     match self {
+    ///       Message::Ping => 0,
+    ///       Message::Upload(_) => 1,
+    ///       Message::Legacy(_) => 2,
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let mut cache = ValidationCache::new(16);
+    ///
+    /// cache.validate_by_discriminant(Message::Upload(vec![1; 1000])).unwrap();
+    /// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    ///
+    /// // a different payload with the same discriminant shares the cache entry
+    /// cache.validate_by_discriminant(Message::Upload(vec![2; 5000])).unwrap();
+    /// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    /// assert_eq!(cache.len(), 1);
+    ///
+    /// cache.validate_by_discriminant(Message::Ping).unwrap();
+    /// assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    ///
+    /// assert!(cache.validate_by_discriminant(Message::Legacy(vec![])).is_err());
+    /// ```
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn validate_by_discriminant<T>(&mut self, value: T) -> Result<Valid<T>, Failure<T>>
+    where
+        T: for<'a> Validate<Context<'a> = ()> + ValidDiscriminant + 'static,
+    {
+        // tagged, so a discriminant doesn't share a hash with a value passed to `validate`
+        let discriminant = ("discriminant", value.valid_discriminant());
+        let key = (TypeId::of::<T>(), self.hasher.hash_one(discriminant));
+        if self.hit(key) {
+            return Ok(Valid(value));
+        }
+
+        let valid = value.validate()?;
+        self.insert(key);
+        Ok(valid)
+    }
+
+    fn key<T: Hash + 'static>(&self, value: &T) -> (TypeId, u64) {
+        (TypeId::of::<T>(), self.hasher.hash_one(value))
+    }
+
+    fn hit(&mut self, key: (TypeId, u64)) -> bool {
+        self.tick += 1;
+        match self.entries.get_mut(&key) {
+            Some(last_used) => {
+                self.by_last_used.remove(last_used);
+                *last_used = self.tick;
+                self.by_last_used.insert(self.tick, key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, key: (TypeId, u64)) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+        match self.entries.insert(key, self.tick) {
+            Some(last_used) => {
+                self.by_last_used.remove(&last_used);
+            }
+            None if self.entries.len() > self.capacity => self.evict(),
+            None => {}
+        }
+        self.by_last_used.insert(self.tick, key);
+    }
+
+    fn evict(&mut self) {
+        if let Some((_, oldest)) = self.by_last_used.pop_first() {
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// A cheap key which fully determines whether a value is valid
+///
+/// This is only available with the `std` feature enabled.
+///
+/// Implementing this trait is a promise that any two values with the same discriminant are either
+/// both valid or both invalid (typically, because validity depends only on an enum's variant). It
+/// allows [`ValidationCache::validate_by_discriminant`] to cache validity without hashing the
+/// whole value.
+///
+/// Validity must depend only on the discriminant. If `is_valid` reads any state which the
+/// discriminant doesn't cover (for example, the payload of a variant), an invalid value can share
+/// a cache entry with an earlier valid value, and it will be returned as `Valid` without being
+/// checked.
+pub trait ValidDiscriminant {
+    /// The key for this value
+    fn valid_discriminant(&self) -> u64;
+}
+
+pub(crate) fn validate_cached_global<T>(value: T) -> Result<Valid<T>, Failure<T>>
+where
+    T: for<'a> Validate<Context<'a> = ()> + Hash + 'static,
+{
+    let lock = || {
+        ValidationCache::global()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    };
+
+    // don't hold the lock while validating, since validation may be slow
+    let (key, hit) = {
+        let mut cache = lock();
+        let key = cache.key(&value);
+        (key, cache.hit(key))
+    };
+
+    if hit {
+        return Ok(Valid(value));
+    }
+
+    let valid = value.validate()?;
+    lock().insert(key);
+    Ok(valid)
+}
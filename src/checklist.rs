@@ -0,0 +1,83 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{Debug, Formatter};
+
+type Check<'a, T, E> = Box<dyn Fn(&T) -> Result<(), E> + 'a>;
+
+/// A list of checks which are run cheapest first, stopping at the first failure
+///
+/// Each check is registered with a cost hint. Checks are run in order of increasing cost (checks
+/// with the same cost run in the order they were added), so the expensive checks are skipped
+/// whenever a cheap check has already rejected the value, regardless of the order they were
+/// written in:
+/// ```
+/// # use validity::*;
+/// # use std::cell::Cell;
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidUsername {
+///   Empty,
+///   Taken,
+/// }
+///
+/// let lookups = Cell::new(0);
+///
+/// let checks = CheckList::new()
+///   .check(100, |name: &Username| {
+///     // pretend this is a database lookup
+///     lookups.set(lookups.get() + 1);
+///     match name.0 == "admin" {
+///       true => Err(InvalidUsername::Taken),
+///       false => Ok(()),
+///     }
+///   })
+///   .check(1, |name| match name.0.is_empty() {
+///     true => Err(InvalidUsername::Empty),
+///     false => Ok(()),
+///   });
+///
+/// assert_eq!(checks.run(&Username(String::new())), Err(InvalidUsername::Empty));
+/// assert_eq!(lookups.get(), 0);  // the expensive check was skipped
+///
+/// assert_eq!(checks.run(&Username("admin".to_string())), Err(InvalidUsername::Taken));
+/// assert_eq!(checks.run(&Username("alice".to_string())), Ok(()));
+/// assert_eq!(lookups.get(), 2);
+/// ```
+pub struct CheckList<'a, T, E> {
+    checks: Vec<(u32, Check<'a, T, E>)>,
+}
+
+impl<'a, T, E> CheckList<'a, T, E> {
+    /// Create a new, empty list of checks
+    pub fn new() -> Self {
+        Self { checks: Vec::new() }
+    }
+
+    /// Add a check, with a hint of how expensive it is to run
+    ///
+    /// Cost hints are only compared with each other, so any consistent scale can be used
+    pub fn check(mut self, cost: u32, check: impl Fn(&T) -> Result<(), E> + 'a) -> Self {
+        let index = self.checks.partition_point(|(c, _)| *c <= cost);
+        self.checks.insert(index, (cost, Box::new(check)));
+        self
+    }
+
+    /// Run the checks cheapest first, returning the error from the first check which fails
+    pub fn run(&self, value: &T) -> Result<(), E> {
+        self.checks.iter().try_for_each(|(_, check)| check(value))
+    }
+}
+
+impl<T, E> Default for CheckList<'_, T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Debug for CheckList<'_, T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let costs: Vec<_> = self.checks.iter().map(|(cost, _)| cost).collect();
+        f.debug_struct("CheckList").field("costs", &costs).finish()
+    }
+}
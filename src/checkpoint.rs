@@ -0,0 +1,298 @@
+use core::{
+    fmt::{Display, Formatter},
+    panic::Location,
+};
+
+/// Records the last check reached inside a validator, so failures can be traced to a specific
+/// check
+///
+/// This is only available with the `checkpoints` feature enabled.
+///
+/// Wrap the body of [`is_valid`](crate::Validate::is_valid) in [`Checkpoints::trace`], and mark
+/// each check with the [`checkpoint!`](crate::checkpoint) macro. If the body returns an error, it
+/// is labelled with the last checkpoint reached (and where it is in the source):
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = Labelled<&'static str>;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     Checkpoints::trace(|cp| {
+///       checkpoint!(cp, "length");
+///       if self.0.len() > 16 {
+///         return Err("invalid username");
+///       }
+///
+///       checkpoint!(cp, "charset");
+///       if !self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+///         return Err("invalid username");
+///       }
+///
+///       Ok(())
+///     })
+///   }
+/// }
+///
+/// let error = Username("hello world".into()).validate().unwrap_err().error;
+/// assert_eq!(error.label, Some("charset"));
+/// assert_eq!(error.error, "invalid username");
+/// assert_eq!(error.to_string(), "invalid username (at check `charset`)");
+///
+/// let error = Username("a".repeat(20)).validate().unwrap_err().error;
+/// assert_eq!(error.label, Some("length"));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Checkpoints {
+    last: Option<(&'static str, &'static Location<'static>)>,
+    #[cfg(feature = "profile")]
+    section: Option<(&'static str, std::time::Instant)>,
+}
+
+impl Checkpoints {
+    /// Create a new tracker, with no checkpoints reached
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` with a new tracker, labelling any error with the last checkpoint reached
+    pub fn trace<E>(f: impl FnOnce(&mut Self) -> Result<(), E>) -> Result<(), Labelled<E>> {
+        let mut checkpoints = Self::new();
+        let result = f(&mut checkpoints);
+
+        #[cfg(feature = "profile")]
+        checkpoints.close_section();
+
+        result.map_err(|error| checkpoints.label(error))
+    }
+
+    /// Mark `label` as the last checkpoint reached
+    ///
+    /// This is usually called via [`checkpoint!`](crate::checkpoint).
+    #[track_caller]
+    pub fn reach(&mut self, label: &'static str) {
+        self.last = Some((label, Location::caller()));
+
+        #[cfg(feature = "profile")]
+        if profile::is_active() {
+            self.close_section();
+            self.section = Some((label, std::time::Instant::now()));
+        }
+    }
+
+    #[cfg(feature = "profile")]
+    fn close_section(&mut self) {
+        if let Some((label, start)) = self.section.take() {
+            profile::record(label, start.elapsed());
+        }
+    }
+
+    /// The label of the last checkpoint reached, if any
+    pub fn last(&self) -> Option<&'static str> {
+        self.last.map(|(label, _)| label)
+    }
+
+    /// Attach the last checkpoint reached to `error`
+    pub fn label<E>(&self, error: E) -> Labelled<E> {
+        Labelled {
+            label: self.last.map(|(label, _)| label),
+            location: self.last.map(|(_, location)| location),
+            error,
+        }
+    }
+}
+
+/// An error labelled with the last checkpoint reached before it was produced
+///
+/// This is only available with the `checkpoints` feature enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Labelled<E> {
+    /// The label of the last checkpoint reached, or `None` if the error occurred before any
+    pub label: Option<&'static str>,
+    /// The source location of the last checkpoint reached
+    pub location: Option<&'static Location<'static>>,
+    /// The underlying error
+    pub error: E,
+}
+
+impl<E: Display> Display for Labelled<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.label {
+            Some(label) => write!(f, "{} (at check `{label}`)", self.error),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> std::error::Error for Labelled<E> {}
+
+/// Mark a checkpoint in a validator
+///
+/// This is only available with the `checkpoints` feature enabled.
+///
+/// `checkpoint!(cp, "label")` records `"label"` (and the location of the macro call) as the last
+/// check reached by `cp`. See [`Checkpoints`] for an example.
+#[macro_export]
+macro_rules! checkpoint {
+    ($checkpoints:expr, $label:expr $(,)?) => {
+        $checkpoints.reach($label)
+    };
+}
+
+/// The time spent in each labelled section of a validator
+///
+/// This is only available with the `profile` feature enabled.
+///
+/// Each [`checkpoint!`](crate::checkpoint) starts a section, which lasts until the next checkpoint
+/// or the end of [`Checkpoints::trace`]. [`ValidationProfile::capture`] records every section
+/// run on the current thread while it is running, so validators don't need to change to be
+/// profiled:
+/// ```
+/// # use validity::*;
+/// # use std::time::Duration;
+/// #[derive(Debug)]
+/// struct Document(String);
+///
+/// impl Validate for Document {
+///   type Context<'a> = ();
+///   type Error = Labelled<&'static str>;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     Checkpoints::trace(|cp| {
+///       checkpoint!(cp, "length");
+///       if self.0.is_empty() {
+///         return Err("empty document");
+///       }
+///
+///       checkpoint!(cp, "spellcheck");
+///       std::thread::sleep(Duration::from_millis(50));
+///
+///       Ok(())
+///     })
+///   }
+/// }
+///
+/// let (result, profile) = ValidationProfile::capture(|| Document("hello".into()).validate());
+/// assert!(result.is_ok());
+///
+/// assert_eq!(profile.sections().len(), 2);
+/// assert!(profile.duration_of("spellcheck") >= Duration::from_millis(50));
+/// assert!(profile.duration_of("spellcheck") > profile.total() * 9 / 10);
+/// ```
+#[cfg(feature = "profile")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationProfile {
+    sections: alloc::vec::Vec<(&'static str, std::time::Duration)>,
+}
+
+#[cfg(feature = "profile")]
+impl ValidationProfile {
+    /// Run `f`, recording the duration of every labelled section run on this thread
+    ///
+    /// Captures can be nested, in which case sections are only recorded by the innermost capture.
+    /// If `f` panics, the enclosing capture (if any) is restored as the panic unwinds:
+    /// ```
+    /// # use validity::*;
+    /// # use std::panic::catch_unwind;
+    /// fn check(label: &'static str) {
+    ///   Checkpoints::trace(|cp| {
+    ///     checkpoint!(cp, label);
+    ///     Ok::<_, ()>(())
+    ///   })
+    ///   .unwrap();
+    /// }
+    ///
+    /// let ((), outer) = ValidationProfile::capture(|| {
+    ///   check("before");
+    ///
+    ///   let panicked = catch_unwind(|| ValidationProfile::capture(|| panic!("oops")));
+    ///   assert!(panicked.is_err());
+    ///
+    ///   // recorded by the outer capture, not the abandoned inner one
+    ///   check("after");
+    /// });
+    ///
+    /// let labels: Vec<_> = outer.sections().iter().map(|(label, _)| *label).collect();
+    /// assert_eq!(labels, ["before", "after"]);
+    /// ```
+    pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Self) {
+        let capture = profile::Capture::start();
+        let result = f();
+        (result, capture.finish())
+    }
+
+    /// Every section recorded, in the order they finished
+    pub fn sections(&self) -> &[(&'static str, std::time::Duration)] {
+        &self.sections
+    }
+
+    /// The total time spent in sections with the given label
+    pub fn duration_of(&self, label: &str) -> std::time::Duration {
+        self.sections
+            .iter()
+            .filter(|(section, _)| *section == label)
+            .map(|(_, duration)| *duration)
+            .sum()
+    }
+
+    /// The total time spent in all sections
+    pub fn total(&self) -> std::time::Duration {
+        self.sections.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+#[cfg(feature = "profile")]
+mod profile {
+    use core::cell::RefCell;
+    use std::time::Duration;
+
+    use super::ValidationProfile;
+
+    std::thread_local! {
+        static ACTIVE: RefCell<Option<ValidationProfile>> = const { RefCell::new(None) };
+    }
+
+    pub(super) fn is_active() -> bool {
+        ACTIVE.with(|active| active.borrow().is_some())
+    }
+
+    pub(super) fn record(label: &'static str, duration: Duration) {
+        ACTIVE.with(|active| {
+            if let Some(profile) = active.borrow_mut().as_mut() {
+                profile.sections.push((label, duration));
+            }
+        });
+    }
+
+    /// An active capture, which restores the enclosing capture (if any) when it is finished or
+    /// dropped, so a panic can't leave it active
+    pub(super) struct Capture {
+        outer: Option<Option<ValidationProfile>>,
+    }
+
+    impl Capture {
+        pub(super) fn start() -> Self {
+            let outer = ACTIVE.with(|active| active.replace(Some(ValidationProfile::default())));
+            Self { outer: Some(outer) }
+        }
+
+        pub(super) fn finish(mut self) -> ValidationProfile {
+            let outer = self.outer.take().flatten();
+            ACTIVE
+                .with(|active| active.replace(outer))
+                .unwrap_or_default()
+        }
+    }
+
+    impl Drop for Capture {
+        fn drop(&mut self) {
+            if let Some(outer) = self.outer.take() {
+                ACTIVE.with(|active| active.replace(outer));
+            }
+        }
+    }
+}
@@ -0,0 +1,894 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{btree_map, BTreeMap, BTreeSet, BinaryHeap},
+    vec::Vec,
+};
+use core::fmt::{Debug, Formatter};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap, HashSet};
+
+use crate::{Failure, Valid, Validate};
+
+/// A marker for collections which are valid exactly when each of their elements is valid
+///
+/// Implementing this trait is a promise that the [`Validate`](crate::Validate) impl for `Self` (if any) checks
+/// nothing beyond the validity of each element. In other words, a collection of valid elements is
+/// always a valid collection, and vice versa.
+///
+/// This allows a `Valid<Self>` to be collected from an iterator of `Valid<T>` without validating
+/// the collection a second time.
+///
+/// If the collection has an invariant of its own (for example, "contains no duplicates"), it must
+/// not implement this trait, since collecting valid elements could produce an invalid collection.
+pub trait ValidCollection<T>: FromIterator<T> {}
+
+impl<T, C> FromIterator<Valid<T>> for Valid<C>
+where
+    C: ValidCollection<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Valid<T>>>(iter: I) -> Self {
+        Valid(iter.into_iter().map(Valid::into_inner).collect())
+    }
+}
+
+/// A `Vec<T>` is valid if every element is valid
+///
+/// Since this is all that is checked, a `Valid<Vec<T>>` can be collected directly from valid
+/// elements:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Even(u32);
+///
+/// impl Validate for Even {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 % 2 {
+///       0 => Ok(()),
+///       _ => Err(()),
+///     }
+///   }
+/// }
+///
+/// let evens: Valid<Vec<Even>> = [2, 4, 6]
+///   .into_iter()
+///   .map(|i| Even(i).validate().unwrap())
+///   .collect();
+///
+/// assert_eq!(evens.len(), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ValidCollection<T> for Vec<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Valid<Vec<T>> {
+    /// Convert each element into a `U`, and validate it
+    ///
+    /// This stops at the first element which fails validation, and returns its index along with
+    /// the error it produced:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct NonEmpty(String);
+    ///
+    /// impl Validate for NonEmpty {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.is_empty() {
+    ///       true => Err(()),
+    ///       false => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Email(String);
+    ///
+    /// impl Validate for Email {
+    ///   type Context<'a> = ();
+    ///   type Error = &'static str;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.contains('@') {
+    ///       true => Ok(()),
+    ///       false => Err("missing @"),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let strings = vec![NonEmpty("a@example.com".to_string()), NonEmpty("b@example.com".to_string())];
+    /// let strings = strings.validate().unwrap();
+    /// let emails = strings.map_each(|s| Email(s.0)).unwrap();
+    /// assert_eq!(emails[1].0, "b@example.com");
+    ///
+    /// let strings = vec![NonEmpty("a@example.com".to_string()), NonEmpty("b".to_string())];
+    /// let strings = strings.validate().unwrap();
+    /// let (index, error) = strings.map_each(|s| Email(s.0)).unwrap_err();
+    /// assert_eq!(index, 1);
+    /// assert_eq!(error, "missing @");
+    /// ```
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn map_each<U, F>(self, mut f: F) -> Result<Valid<Vec<U>>, (usize, U::Error)>
+    where
+        U: for<'a> Validate<Context<'a> = ()>,
+        F: FnMut(T) -> U,
+    {
+        self.into_inner()
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| f(item).validate().map_err(|e| (index, e.error)))
+            .collect()
+    }
+}
+
+/// A `Vec<T>` is valid if every element is valid
+///
+/// The context is cloned for each element. If validation fails, the error contains the index of
+/// the first invalid element, along with the error it produced.
+#[cfg(feature = "alloc")]
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+    for<'a> T::Context<'a>: Clone,
+{
+    type Context<'a> = T::Context<'a>;
+    type Error = (usize, T::Error);
+
+    fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        self.iter()
+            .enumerate()
+            .try_for_each(|(index, item)| item.is_valid(ctx.clone()).map_err(|e| (index, e)))
+    }
+}
+
+/// An array is valid if every element is valid
+///
+/// Like the `Validate` impl for `Vec<T>`, the context is cloned for each element, and the error
+/// contains the index of the first invalid element. This doesn't allocate, so it is available
+/// without the `alloc` feature.
+impl<T, const N: usize> Validate for [T; N]
+where
+    T: Validate,
+    for<'a> T::Context<'a>: Clone,
+{
+    type Context<'a> = T::Context<'a>;
+    type Error = (usize, T::Error);
+
+    fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        self.iter()
+            .enumerate()
+            .try_for_each(|(index, item)| item.is_valid(ctx.clone()).map_err(|e| (index, e)))
+    }
+}
+
+/// Validate every element of an array, keeping its length in the type
+///
+/// This is the `Validate` impl for `[T; N]`, but returns only the index and error of the first
+/// invalid element, rather than a [`Failure`](crate::Failure) containing the whole array:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Channel(u8);
+///
+/// impl Validate for Channel {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       1..=16 => Ok(()),
+///       _ => Err("channel out of range"),
+///     }
+///   }
+/// }
+///
+/// let channels: Valid<[Channel; 3]> = validate_array([Channel(1), Channel(2), Channel(3)], ()).unwrap();
+/// assert_eq!(channels[2].0, 3);
+///
+/// let error = validate_array([Channel(1), Channel(17), Channel(3)], ()).unwrap_err();
+/// assert_eq!(error, (1, "channel out of range"));
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_array<'c, T, const N: usize>(
+    array: [T; N],
+    ctx: T::Context<'c>,
+) -> Result<Valid<[T; N]>, (usize, T::Error)>
+where
+    T: Validate,
+    for<'a> T::Context<'a>: Clone,
+{
+    array.validate_with(ctx).map_err(|failure| failure.error)
+}
+
+/// Validate each item of an iterator in order, reusing the same mutable context for every item
+///
+/// Unlike the `Validate` impl for `Vec<T>`, the context doesn't need to be `Clone`, so this is
+/// suitable for contexts which can only be borrowed mutably (for example, a database
+/// transaction). Since the items are validated one at a time, each item sees any changes made to
+/// the context by the items before it.
+///
+/// If validation fails, the error contains the index of the first invalid item, along with its
+/// [`Failure`](crate::Failure). Items after the first invalid item are not validated.
+/// ```
+/// # use validity::*;
+/// # use std::collections::HashSet;
+/// struct Transaction {
+///   reserved: HashSet<String>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// #[derive(Debug)]
+/// struct AlreadyTaken;
+///
+/// impl Validate for Username {
+///   type Context<'a> = &'a mut Transaction;
+///   type Error = AlreadyTaken;
+///
+///   fn is_valid(&self, tx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match tx.reserved.insert(self.0.clone()) {
+///       true => Ok(()),
+///       false => Err(AlreadyTaken),
+///     }
+///   }
+/// }
+///
+/// let mut tx = Transaction { reserved: HashSet::new() };
+///
+/// let names = ["alice", "bob"].map(|s| Username(s.to_string()));
+/// let names = validate_all_seq(names, &mut tx).unwrap();
+/// assert_eq!(names.len(), 2);
+/// assert_eq!(tx.reserved.len(), 2);
+///
+/// let names = ["carol", "alice"].map(|s| Username(s.to_string()));
+/// let (index, failure) = validate_all_seq(names, &mut tx).unwrap_err();
+/// assert_eq!(index, 1);
+/// assert_eq!(failure.value.0, "alice");
+/// assert!(tx.reserved.contains("carol"));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_all_seq<T, I, C>(
+    iter: I,
+    ctx: &mut C,
+) -> Result<Vec<Valid<T>>, (usize, crate::Failure<T>)>
+where
+    I: IntoIterator<Item = T>,
+    T: for<'a> Validate<Context<'a> = &'a mut C>,
+    C: ?Sized,
+{
+    iter.into_iter()
+        .enumerate()
+        .map(|(index, item)| item.validate_with(ctx).map_err(|e| (index, e)))
+        .collect()
+}
+
+/// Validate each item of an iterator in order, copying the same context for every item
+///
+/// Unlike the `Validate` impl for `Vec<T>`, this requires the context to be `Copy` rather than
+/// `Clone`, which guarantees it is cheap to pass to each item. This is the case for contexts which
+/// are shared references (for example, to a large configuration):
+/// ```
+/// # use validity::*;
+/// // `Config` is only ever borrowed, so it doesn't need to be `Clone`
+/// struct Config {
+///   banned: Vec<String>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = &'a Config;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, config: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match config.banned.contains(&self.0) {
+///       true => Err("banned username"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let config = Config { banned: vec!["root".to_string()] };
+///
+/// let names = ["alice", "bob"].map(|s| Username(s.to_string()));
+/// let names = validate_all_shared(names, &config).unwrap();
+/// assert_eq!(names.len(), 2);
+///
+/// let names = ["alice", "root"].map(|s| Username(s.to_string()));
+/// let (index, failure) = validate_all_shared(names, &config).unwrap_err();
+/// assert_eq!(index, 1);
+/// assert_eq!(failure.error, "banned username");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_all_shared<'c, T, I>(
+    iter: I,
+    ctx: T::Context<'c>,
+) -> Result<Vec<Valid<T>>, (usize, crate::Failure<T>)>
+where
+    I: IntoIterator<Item = T>,
+    T: Validate,
+    T::Context<'c>: Copy,
+{
+    iter.into_iter()
+        .enumerate()
+        .map(|(index, item)| item.validate_with(ctx).map_err(|e| (index, e)))
+        .collect()
+}
+
+/// Validate each item of an iterator in order, with a context that depends on the item's index
+///
+/// `ctx` is called with the index of each item to produce its context. This is useful when
+/// validity depends on position, for example the header row of a CSV file.
+///
+/// If validation fails, the error contains the index of the first invalid item, along with the
+/// error it produced. Items after the first invalid item are not validated.
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Row(Vec<&'static str>);
+///
+/// enum RowKind {
+///   Header,
+///   Data,
+/// }
+///
+/// impl Validate for Row {
+///   type Context<'a> = RowKind;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, kind: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match kind {
+///       RowKind::Header if self.0.iter().any(|cell| cell.is_empty()) => Err("empty column name"),
+///       RowKind::Data if self.0.iter().any(|cell| cell.parse::<f64>().is_err()) => Err("not a number"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let kind = |index| match index {
+///   0 => RowKind::Header,
+///   _ => RowKind::Data,
+/// };
+///
+/// let rows = [Row(vec!["x", "y"]), Row(vec!["1", "2.5"]), Row(vec!["3", "4"])];
+/// let rows = validate_enumerated(rows, kind).unwrap();
+/// assert_eq!(rows.len(), 3);
+///
+/// let rows = [Row(vec!["x", "y"]), Row(vec!["1", "2.5"]), Row(vec!["x", "4"])];
+/// assert_eq!(validate_enumerated(rows, kind).unwrap_err(), (2, "not a number"));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_enumerated<'c, T, I, F>(
+    iter: I,
+    mut ctx: F,
+) -> Result<Vec<Valid<T>>, (usize, T::Error)>
+where
+    I: IntoIterator<Item = T>,
+    T: Validate,
+    F: FnMut(usize) -> T::Context<'c>,
+{
+    iter.into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.validate_with(ctx(index))
+                .map_err(|failure| (index, failure.error))
+        })
+        .collect()
+}
+
+/// Remove elements with duplicate keys, keeping the first occurrence of each key
+///
+/// Removing elements can't make the remaining elements invalid, so they are not re-validated. The
+/// order of the remaining elements is preserved:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Email(String);
+///
+/// impl Validate for Email {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.contains('@') {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// let emails = ["b@example.com", "A@example.com", "a@example.com", "b@example.com"]
+///   .into_iter()
+///   .map(|email| Email(email.to_string()).validate().unwrap())
+///   .collect();
+///
+/// let emails: Vec<Valid<Email>> = dedup_valid_by_key(emails, |email| email.0.to_lowercase());
+/// let emails: Vec<&str> = emails.iter().map(|email| email.0.as_str()).collect();
+/// assert_eq!(emails, ["b@example.com", "A@example.com"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn dedup_valid_by_key<T, K, F>(mut items: Vec<Valid<T>>, mut key: F) -> Vec<Valid<T>>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut seen = BTreeSet::new();
+    items.retain(|item| seen.insert(key(&item.0)));
+    items
+}
+
+/// Fold an iterator into an accumulator, validating the accumulator after each step
+///
+/// `f` produces the next accumulator from the current one and the next item. Folding stops at the
+/// first accumulator which fails validation, returning the last valid accumulator along with the
+/// index of the item which made it invalid. This models budgets and quotas, where each item uses
+/// up some of a limited resource:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Spend(u32);
+///
+/// impl Validate for Spend {
+///   type Context<'a> = u32;  // the budget
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, budget: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 <= budget {
+///       true => Ok(()),
+///       false => Err("over budget"),
+///     }
+///   }
+/// }
+///
+/// let start = Spend(0).validate_with(100).unwrap();
+/// let total = validate_scan([10, 20, 30], start, |spend, cost| Spend(spend.0 + cost), 100).unwrap();
+/// assert_eq!(total.0, 60);
+///
+/// let start = Spend(0).validate_with(100).unwrap();
+/// let stopped = validate_scan([40, 50, 30, 5], start, |spend, cost| Spend(spend.0 + cost), 100)
+///   .unwrap_err();
+/// assert_eq!(stopped.index, 2);
+/// assert_eq!(stopped.last_valid.0, 90);
+/// assert_eq!(stopped.failure.value.0, 120);
+/// assert_eq!(stopped.failure.error, "over budget");
+/// ```
+/// Items after the one which made the accumulator invalid are not consumed.
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_scan<'c, I, Acc, F>(
+    iter: I,
+    init: Valid<Acc>,
+    mut f: F,
+    ctx: Acc::Context<'c>,
+) -> Result<Valid<Acc>, ScanStopped<Acc>>
+where
+    I: IntoIterator,
+    Acc: Validate,
+    Acc::Context<'c>: Clone,
+    F: FnMut(&Acc, I::Item) -> Acc,
+{
+    let mut acc = init;
+
+    for (index, item) in iter.into_iter().enumerate() {
+        match f(&acc.0, item).validate_with(ctx.clone()) {
+            Ok(next) => acc = next,
+            Err(failure) => {
+                return Err(ScanStopped {
+                    index,
+                    last_valid: acc,
+                    failure,
+                })
+            }
+        }
+    }
+
+    Ok(acc)
+}
+
+/// The error returned by [`validate_scan`] when the accumulator becomes invalid
+pub struct ScanStopped<Acc: Validate> {
+    /// The index of the item which made the accumulator invalid
+    pub index: usize,
+    /// The accumulator before that item was applied
+    pub last_valid: Valid<Acc>,
+    /// The invalid accumulator, and its error
+    pub failure: Failure<Acc>,
+}
+
+impl<Acc> Debug for ScanStopped<Acc>
+where
+    Acc: Validate + Debug,
+    Acc::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScanStopped")
+            .field("index", &self.index)
+            .field("last_valid", &self.last_valid)
+            .field("failure", &self.failure)
+            .finish()
+    }
+}
+
+/// Lookups of raw values in a set of valid values
+///
+/// Since `Valid<T>` implements `Borrow<T>` (and hashes and compares exactly like `T`), a set of
+/// `Valid<T>` can be queried with a `&T` directly. This trait gives that lookup a clearer name:
+/// ```
+/// # use validity::*;
+/// # use std::collections::BTreeSet;
+/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.is_empty() {
+///       true => Err(()),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let valid: BTreeSet<Valid<Username>> = ["alice", "bob"]
+///   .into_iter()
+///   .map(|s| Username(s.to_string()).validate().unwrap())
+///   .collect();
+///
+/// let raw: BTreeSet<Username> = ["bob", "carol"]
+///   .into_iter()
+///   .map(|s| Username(s.to_string()))
+///   .collect();
+///
+/// assert!(valid.contains_raw(&Username("alice".to_string())));
+/// assert!(!valid.contains_raw(&Username("carol".to_string())));
+///
+/// let both: Vec<_> = raw.iter().filter(|name| valid.contains_raw(name)).collect();
+/// assert_eq!(both, [&Username("bob".to_string())]);
+///
+/// let bob = Username("bob".to_string()).validate().unwrap();
+/// assert!(raw.contains(&*bob));
+/// assert!(valid.contains(&bob));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ValidSet<T> {
+    /// Returns `true` if the set contains a `Valid<T>` equal to `value`
+    fn contains_raw(&self, value: &T) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<T, S> ValidSet<T> for HashSet<Valid<T>, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn contains_raw(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> ValidSet<T> for BTreeSet<Valid<T>> {
+    fn contains_raw(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+}
+
+/// Searches of a slice of valid values, with a custom equality
+///
+/// `contains` and `position` on a `[Valid<T>]` use `T`'s `PartialEq`. These methods take an
+/// equality function instead, which is called with the inner value of each element and the
+/// needle. The needle doesn't need to be a `T`, so a raw value can be searched for directly:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Email(String);
+///
+/// impl Validate for Email {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.contains('@') {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// let emails: Vec<Valid<Email>> = ["alice@example.com", "Bob@Example.com"]
+///   .into_iter()
+///   .map(|email| Email(email.to_string()).validate().unwrap())
+///   .collect();
+///
+/// let same_address = |email: &Email, needle: &str| email.0.eq_ignore_ascii_case(needle);
+///
+/// assert!(emails.contains_by("ALICE@example.com", same_address));
+/// assert_eq!(emails.position_by("bob@example.com", same_address), Some(1));
+/// assert_eq!(emails.position_by("carol@example.com", same_address), None);
+/// ```
+pub trait ValidSlice<T> {
+    /// Returns `true` if `eq` returns `true` for any element and `needle`
+    fn contains_by<U, F>(&self, needle: &U, eq: F) -> bool
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U) -> bool,
+    {
+        self.position_by(needle, eq).is_some()
+    }
+
+    /// Returns the index of the first element for which `eq` returns `true` with `needle`
+    fn position_by<U, F>(&self, needle: &U, eq: F) -> Option<usize>
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U) -> bool;
+}
+
+impl<T> ValidSlice<T> for [Valid<T>] {
+    fn position_by<U, F>(&self, needle: &U, mut eq: F) -> Option<usize>
+    where
+        U: ?Sized,
+        F: FnMut(&T, &U) -> bool,
+    {
+        self.iter().position(|item| eq(&item.0, needle))
+    }
+}
+
+/// An entry API for maps of validated values, which only validates values that are inserted
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// `entry_valid` is the equivalent of `entry`, and [`ValidEntry::or_insert_with_validated`] only
+/// creates and validates a value if the key is missing:
+/// ```
+/// # use validity::*;
+/// # use std::{cell::Cell, collections::BTreeMap};
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err("port must be non-zero"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let mut ports: BTreeMap<&str, Valid<Port>> = BTreeMap::new();
+/// ports.insert("http", Port(80).validate().unwrap());
+///
+/// let created = Cell::new(0);
+/// let make = |port| {
+///   created.set(created.get() + 1);
+///   Port(port)
+/// };
+///
+/// // the key exists, so no value is created (or validated)
+/// let http = ports.entry_valid("http").or_insert_with_validated(|| make(0), ()).unwrap();
+/// assert_eq!(http.0, 80);
+/// assert_eq!(created.get(), 0);
+///
+/// // the key is missing, so the new value is validated and inserted
+/// let https = ports.entry_valid("https").or_insert_with_validated(|| make(443), ()).unwrap();
+/// assert_eq!(https.0, 443);
+/// assert_eq!(created.get(), 1);
+///
+/// // an invalid new value is returned, and nothing is inserted
+/// let failure = ports.entry_valid("ftp").or_insert_with_validated(|| make(0), ()).unwrap_err();
+/// assert_eq!(failure.error, "port must be non-zero");
+/// assert!(!ports.contains_key("ftp"));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ValidMap<K, V> {
+    /// The map's entry type for a missing key
+    type Vacant<'a>: VacantValidEntry<'a, V>
+    where
+        Self: 'a;
+
+    /// Get the entry for `key`, to insert a value which is validated only if needed
+    fn entry_valid(&mut self, key: K) -> ValidEntry<'_, V, Self::Vacant<'_>>;
+}
+
+/// An entry in a map of validated values, returned by [`ValidMap::entry_valid`]
+///
+/// This is only available with the `alloc` feature enabled.
+#[cfg(feature = "alloc")]
+pub enum ValidEntry<'a, V, E> {
+    /// The key already has a value
+    Occupied(&'a mut Valid<V>),
+    /// The key is missing
+    Vacant(E),
+}
+
+/// A map entry for a missing key, which a `Valid<V>` can be inserted into
+///
+/// This is only available with the `alloc` feature enabled.
+#[cfg(feature = "alloc")]
+pub trait VacantValidEntry<'a, V> {
+    /// Insert `value` into the map, returning a reference to it
+    fn insert_valid(self, value: Valid<V>) -> &'a mut Valid<V>;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, V, E: VacantValidEntry<'a, V>> ValidEntry<'a, V, E> {
+    /// Return the existing value, or create, validate and insert a new one if the key is missing
+    ///
+    /// If the new value is invalid, nothing is inserted and the [`Failure`] is returned
+    pub fn or_insert_with_validated(
+        self,
+        f: impl FnOnce() -> V,
+        ctx: V::Context<'_>,
+    ) -> Result<&'a mut Valid<V>, Failure<V>>
+    where
+        V: Validate,
+    {
+        match self {
+            Self::Occupied(value) => Ok(value),
+            Self::Vacant(entry) => Ok(entry.insert_valid(f().validate_with(ctx)?)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V> VacantValidEntry<'a, V> for hash_map::VacantEntry<'a, K, Valid<V>> {
+    fn insert_valid(self, value: Valid<V>) -> &'a mut Valid<V> {
+        self.insert(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord, V> VacantValidEntry<'a, V> for btree_map::VacantEntry<'a, K, Valid<V>> {
+    fn insert_valid(self, value: Valid<V>) -> &'a mut Valid<V> {
+        self.insert(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> ValidMap<K, V> for HashMap<K, Valid<V>, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Vacant<'a>
+        = hash_map::VacantEntry<'a, K, Valid<V>>
+    where
+        Self: 'a;
+
+    fn entry_valid(&mut self, key: K) -> ValidEntry<'_, V, Self::Vacant<'_>> {
+        match self.entry(key) {
+            hash_map::Entry::Occupied(entry) => ValidEntry::Occupied(entry.into_mut()),
+            hash_map::Entry::Vacant(entry) => ValidEntry::Vacant(entry),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> ValidMap<K, V> for BTreeMap<K, Valid<V>> {
+    type Vacant<'a>
+        = btree_map::VacantEntry<'a, K, Valid<V>>
+    where
+        Self: 'a;
+
+    fn entry_valid(&mut self, key: K) -> ValidEntry<'_, V, Self::Vacant<'_>> {
+        match self.entry(key) {
+            btree_map::Entry::Occupied(entry) => ValidEntry::Occupied(entry.into_mut()),
+            btree_map::Entry::Vacant(entry) => ValidEntry::Vacant(entry),
+        }
+    }
+}
+
+/// A priority queue which only contains valid values
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// This wraps a [`BinaryHeap<Valid<T>>`], validating values as they are pushed. Like
+/// `BinaryHeap`, it is a max-heap, ordered by `T`'s [`Ord`] impl:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Task {
+///   priority: u8,
+///   name: &'static str,
+/// }
+///
+/// impl Validate for Task {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.priority {
+///       1..=10 => Ok(()),
+///       _ => Err("priority must be between 1 and 10"),
+///     }
+///   }
+/// }
+///
+/// let mut heap = ValidHeap::new();
+/// heap.push(Task { priority: 3, name: "write docs" }, ()).unwrap();
+/// heap.push(Task { priority: 9, name: "fix outage" }, ()).unwrap();
+/// heap.push(Task { priority: 5, name: "review PR" }, ()).unwrap();
+///
+/// let failure = heap.push(Task { priority: 42, name: "???" }, ()).unwrap_err();
+/// assert_eq!(failure.error, "priority must be between 1 and 10");
+/// assert_eq!(heap.len(), 3);
+///
+/// let names: Vec<_> = std::iter::from_fn(|| heap.pop()).map(|task| task.name).collect();
+/// assert_eq!(names, ["fix outage", "review PR", "write docs"]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct ValidHeap<T>(BinaryHeap<Valid<T>>);
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> ValidHeap<T> {
+    /// Create an empty heap
+    pub fn new() -> Self {
+        Self(BinaryHeap::new())
+    }
+
+    /// Validate `value` with the given context, and push it onto the heap if it is valid
+    ///
+    /// If it is invalid, the heap is unchanged and the [`Failure`] is returned
+    pub fn push(&mut self, value: T, ctx: T::Context<'_>) -> Result<(), Failure<T>>
+    where
+        T: Validate,
+    {
+        self.0.push(value.validate_with(ctx)?);
+        Ok(())
+    }
+
+    /// Push an already-validated value onto the heap
+    pub fn push_valid(&mut self, value: Valid<T>) {
+        self.0.push(value);
+    }
+
+    /// Remove the greatest value from the heap, and return it
+    pub fn pop(&mut self) -> Option<Valid<T>> {
+        self.0.pop()
+    }
+
+    /// Borrow the greatest value in the heap
+    pub fn peek(&self) -> Option<&Valid<T>> {
+        self.0.peek()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ValidHeap<T> {
+    /// The number of values in the heap
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the heap contains no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Consume self and return the underlying heap
+    pub fn into_inner(self) -> BinaryHeap<Valid<T>> {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> Default for ValidHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,210 @@
+use core::{
+    fmt::{Display, Formatter},
+    ops::{BitAnd, BitOr},
+};
+
+#[cfg(feature = "url")]
+use crate::UrlValidator;
+use crate::{InRange, Validator};
+
+/// A [`Validator`] made from a closure
+///
+/// Create one with [`rule`]. Like the other validators in this crate, rules can be combined with
+/// `&` (both must pass) and `|` (either must pass):
+/// ```
+/// # use validity::*;
+/// fn max_len(max: usize) -> Rule<impl Fn(&str) -> Result<(), String>> {
+///   rule(move |s: &str| match s.len() <= max {
+///     true => Ok(()),
+///     false => Err(format!("must be at most {max} characters")),
+///   })
+/// }
+///
+/// let non_empty = rule(|s: &str| match s.is_empty() {
+///   true => Err("must not be empty".to_string()),
+///   false => Ok(()),
+/// });
+///
+/// let username = non_empty & max_len(30);
+/// assert_eq!(username.check("alice"), Ok(()));
+/// assert_eq!(username.check(""), Err(AndError::Left("must not be empty".to_string())));
+/// assert_eq!(
+///   username.check(&"a".repeat(31)),
+///   Err(AndError::Right("must be at most 30 characters".to_string())),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rule<F>(F);
+
+/// Create a [`Rule`] which runs `f`
+pub fn rule<F>(f: F) -> Rule<F> {
+    Rule(f)
+}
+
+impl<T, E, F> Validator<T> for Rule<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn check(&self, value: &T) -> Result<(), Self::Error> {
+        (self.0)(value)
+    }
+}
+
+/// A [`Validator`] which passes only if both of its validators pass
+///
+/// Create one with `a & b` or [`Validator::and`]. The left validator runs first, and the right
+/// validator only runs if it passes.
+#[derive(Debug, Clone, Copy)]
+pub struct And<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> And<A, B> {
+    /// Combine two validators, both of which must pass
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<T, A, B> Validator<T> for And<A, B>
+where
+    T: ?Sized,
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    type Error = AndError<A::Error, B::Error>;
+
+    fn check(&self, value: &T) -> Result<(), Self::Error> {
+        self.left.check(value).map_err(AndError::Left)?;
+        self.right.check(value).map_err(AndError::Right)
+    }
+}
+
+/// A [`Validator`] which passes if either of its validators pass
+///
+/// Create one with `a | b` or [`Validator::or`]. The right validator only runs if the left
+/// validator fails:
+/// ```
+/// # use validity::*;
+/// let small_or_round = InRange::new(0..10) | rule(|n: &i32| match n % 100 {
+///   0 => Ok(()),
+///   _ => Err("not a multiple of 100"),
+/// });
+///
+/// assert_eq!(small_or_round.check(&5), Ok(()));
+/// assert_eq!(small_or_round.check(&500), Ok(()));
+///
+/// let error = small_or_round.check(&50).unwrap_err();
+/// assert_eq!(error.to_string(), "value must be less than 10; not a multiple of 100");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Or<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> Or<A, B> {
+    /// Combine two validators, either of which must pass
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<T, A, B> Validator<T> for Or<A, B>
+where
+    T: ?Sized,
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    type Error = OrError<A::Error, B::Error>;
+
+    fn check(&self, value: &T) -> Result<(), Self::Error> {
+        let Err(left) = self.left.check(value) else {
+            return Ok(());
+        };
+
+        self.right
+            .check(value)
+            .map_err(|right| OrError { left, right })
+    }
+}
+
+/// The error returned by [`And`], which is the error of whichever validator failed first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AndError<A, B> {
+    /// The left validator failed
+    Left(A),
+    /// The left validator passed, but the right validator failed
+    Right(B),
+}
+
+impl<A: Display, B: Display> Display for AndError<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Left(error) => error.fmt(f),
+            Self::Right(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> std::error::Error for AndError<A, B>
+where
+    A: std::error::Error,
+    B: std::error::Error,
+{
+}
+
+/// The error returned by [`Or`], which contains the errors of both validators, since both failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrError<A, B> {
+    /// The error from the left validator
+    pub left: A,
+    /// The error from the right validator
+    pub right: B,
+}
+
+impl<A: Display, B: Display> Display for OrError<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}; {}", self.left, self.right)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> std::error::Error for OrError<A, B>
+where
+    A: std::error::Error,
+    B: std::error::Error,
+{
+}
+
+macro_rules! impl_ops {
+    ($name:ident $(<$($param:ident),*>)?) => {
+        impl<$($($param,)*)? Rhs> BitAnd<Rhs> for $name$(<$($param),*>)? {
+            type Output = And<Self, Rhs>;
+
+            fn bitand(self, rhs: Rhs) -> Self::Output {
+                And::new(self, rhs)
+            }
+        }
+
+        impl<$($($param,)*)? Rhs> BitOr<Rhs> for $name$(<$($param),*>)? {
+            type Output = Or<Self, Rhs>;
+
+            fn bitor(self, rhs: Rhs) -> Self::Output {
+                Or::new(self, rhs)
+            }
+        }
+    };
+}
+
+impl_ops!(Rule<F>);
+impl_ops!(And<A, B>);
+impl_ops!(Or<A, B>);
+impl_ops!(InRange<T>);
+#[cfg(feature = "url")]
+impl_ops!(UrlValidator);
@@ -0,0 +1,77 @@
+/// A validator which only runs when a flag in the context is set
+///
+/// This keeps rules which are gated behind a runtime feature flag (for example, stricter rules
+/// which are being rolled out gradually) declarative. Create one with [`when`], and call
+/// [`ConditionalValidator::check`] from a `Validate` impl:
+/// ```
+/// # use validity::*;
+/// struct Flags {
+///   strict_passwords: bool,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Password(String);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidPassword {
+///   TooShort,
+///   NoDigit,
+/// }
+///
+/// fn has_digit(password: &Password, _flags: &Flags) -> Result<(), InvalidPassword> {
+///   match password.0.chars().any(|c| c.is_ascii_digit()) {
+///     true => Ok(()),
+///     false => Err(InvalidPassword::NoDigit),
+///   }
+/// }
+///
+/// impl Validate for Password {
+///   type Context<'a> = &'a Flags;
+///   type Error = InvalidPassword;
+///
+///   fn is_valid(&self, flags: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.0.len() < 8 {
+///       return Err(InvalidPassword::TooShort);
+///     }
+///
+///     when(|flags: &Flags| flags.strict_passwords, has_digit).check(self, flags)
+///   }
+/// }
+///
+/// let lenient = Flags { strict_passwords: false };
+/// let strict = Flags { strict_passwords: true };
+///
+/// assert!(Password("password".to_string()).validate_with(&lenient).is_ok());
+///
+/// let failure = Password("password".to_string()).validate_with(&strict).unwrap_err();
+/// assert_eq!(failure.error, InvalidPassword::NoDigit);
+///
+/// assert!(Password("passw0rd".to_string()).validate_with(&strict).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionalValidator<F, V> {
+    flag: F,
+    validator: V,
+}
+
+/// Create a [`ConditionalValidator`] which runs `validator` only if `flag` returns `true` for the
+/// context
+pub fn when<F, V>(flag: F, validator: V) -> ConditionalValidator<F, V> {
+    ConditionalValidator { flag, validator }
+}
+
+impl<F, V> ConditionalValidator<F, V> {
+    /// Run the validator if the flag is set in `ctx`, otherwise return `Ok(())` without running it
+    pub fn check<T, C, E>(&self, value: &T, ctx: &C) -> Result<(), E>
+    where
+        T: ?Sized,
+        C: ?Sized,
+        F: Fn(&C) -> bool,
+        V: Fn(&T, &C) -> Result<(), E>,
+    {
+        match (self.flag)(ctx) {
+            true => (self.validator)(value, ctx),
+            false => Ok(()),
+        }
+    }
+}
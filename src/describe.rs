@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+use crate::Validate;
+
+/// A description of the rules a type enforces, for documentation purposes
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// This is purely informational: validation is still performed by [`Validate::is_valid`], which
+/// should be kept in sync with the rules listed here. A typical use is generating documentation of
+/// the constraints an API enforces:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if !(3..=16).contains(&self.0.len()) {
+///       return Err("E_LENGTH");
+///     }
+///     if !self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+///       return Err("E_CHARSET");
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// impl DescribeValidation for Username {
+///   fn rules() -> Vec<RuleInfo> {
+///     vec![
+///       RuleInfo::new("length", "between 3 and 16 characters", "E_LENGTH"),
+///       RuleInfo::new("charset", "only ASCII letters and digits", "E_CHARSET"),
+///     ]
+///   }
+/// }
+///
+/// let docs: Vec<_> = Username::rules()
+///   .iter()
+///   .map(|rule| format!("{} ({}): {}", rule.name, rule.code, rule.description))
+///   .collect();
+///
+/// assert_eq!(docs, [
+///   "length (E_LENGTH): between 3 and 16 characters",
+///   "charset (E_CHARSET): only ASCII letters and digits",
+/// ]);
+/// ```
+pub trait DescribeValidation: Validate {
+    /// The rules this type enforces, in the order they are checked
+    fn rules() -> Vec<RuleInfo>;
+}
+
+/// A single rule listed by [`DescribeValidation::rules`]
+///
+/// This is only available with the `alloc` feature enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleInfo {
+    /// A short name for the rule
+    pub name: &'static str,
+    /// A human-readable description of what the rule requires
+    pub description: &'static str,
+    /// A machine-readable code for the error produced when the rule fails
+    pub code: &'static str,
+}
+
+impl RuleInfo {
+    /// Create a new `RuleInfo`
+    pub const fn new(name: &'static str, description: &'static str, code: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            code,
+        }
+    }
+}
@@ -0,0 +1,324 @@
+#[cfg(feature = "json-pointer")]
+use alloc::string::String;
+use core::fmt::Display;
+#[cfg(feature = "json-pointer")]
+use core::fmt::{Formatter, Write};
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::{Valid, Validate};
+
+/// Deserialize a `T`, then validate it
+///
+/// This is only available with the `serde` feature enabled.
+///
+/// This is intended to be used with `#[serde(deserialize_with = "validity::deserialize_valid")]`,
+/// to validate individual fields during deserialization. If validation fails, the error is
+/// converted into a deserialization error with [`Error::custom`]:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Port(u16);
+///
+/// struct PortIsZero;
+///
+/// impl std::fmt::Display for PortIsZero {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     write!(f, "port must not be zero")
+///   }
+/// }
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = PortIsZero;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(PortIsZero),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Config {
+///   #[serde(deserialize_with = "validity::deserialize_valid")]
+///   port: Valid<Port>,
+///   fallback_port: Port,  // not validated
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "port": 80, "fallback_port": 0 }"#).unwrap();
+/// assert_eq!(config.port.0, 80);
+///
+/// let error = serde_json::from_str::<Config>(r#"{ "port": 0, "fallback_port": 0 }"#).unwrap_err();
+/// assert!(error.to_string().contains("port must not be zero"));
+/// ```
+pub fn deserialize_valid<'de, D, T>(deserializer: D) -> Result<Valid<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + for<'a> Validate<Context<'a> = ()>,
+    T::Error: Display,
+{
+    T::deserialize(deserializer)?
+        .validate()
+        .map_err(|failure| D::Error::custom(failure.error))
+}
+
+/// Deserialize a `T` with a custom function, then validate it
+///
+/// This is only available with the `serde` feature enabled.
+///
+/// This lets a validated field use a custom format (such as a module normally used with
+/// `#[serde(with = "...")]`). The custom format is applied first, and the result is validated
+/// afterwards, so a value in the right format can still be rejected:
+/// ```
+/// # use validity::*;
+/// # use serde::{de::Error, Deserialize, Deserializer};
+/// #[derive(Debug)]
+/// struct Percent(u8);
+///
+/// impl Validate for Percent {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0..=100 => Ok(()),
+///       _ => Err("percentages must be at most 100"),
+///     }
+///   }
+/// }
+///
+/// // a custom format, for strings like "50%"
+/// fn percent_format<'de, D: Deserializer<'de>>(d: D) -> Result<Percent, D::Error> {
+///   let s = <&str>::deserialize(d)?;
+///   let n = s.strip_suffix('%').ok_or_else(|| D::Error::custom("missing `%`"))?;
+///   n.parse().map(Percent).map_err(D::Error::custom)
+/// }
+///
+/// fn valid_percent<'de, D: Deserializer<'de>>(d: D) -> Result<Valid<Percent>, D::Error> {
+///   validity::deserialize_valid_with(d, percent_format)
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Progress {
+///   #[serde(deserialize_with = "valid_percent")]
+///   done: Valid<Percent>,
+/// }
+///
+/// let progress: Progress = serde_json::from_str(r#"{ "done": "50%" }"#).unwrap();
+/// assert_eq!(progress.done.0, 50);
+///
+/// let error = serde_json::from_str::<Progress>(r#"{ "done": "50" }"#).unwrap_err();
+/// assert!(error.to_string().contains("missing `%`"));
+///
+/// let error = serde_json::from_str::<Progress>(r#"{ "done": "150%" }"#).unwrap_err();
+/// assert!(error.to_string().contains("percentages must be at most 100"));
+/// ```
+pub fn deserialize_valid_with<'de, D, T, F>(deserializer: D, f: F) -> Result<Valid<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    F: FnOnce(D) -> Result<T, D::Error>,
+    T: for<'a> Validate<Context<'a> = ()>,
+    T::Error: Display,
+{
+    f(deserializer)?
+        .validate()
+        .map_err(|failure| D::Error::custom(failure.error))
+}
+
+/// Deserializes a `T`, then validates it (see [`deserialize_valid`])
+///
+/// `T` is fully deserialized before it is validated, so this works with any representation `T`
+/// uses (including internally and adjacently tagged enums, whose content serde buffers first). It
+/// also means any `#[serde(default)]` fields are filled in first, and the defaults are validated
+/// along with everything else:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Config {
+///   host: String,
+///   #[serde(default)]
+///   port: u16,  // defaults to 0, which is invalid
+/// }
+///
+/// impl Validate for Config {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.port {
+///       0 => Err("port must not be zero"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let config: Valid<Config> = serde_json::from_str(r#"{ "host": "localhost", "port": 80 }"#).unwrap();
+/// assert_eq!(config.port, 80);
+///
+/// let error = serde_json::from_str::<Valid<Config>>(r#"{ "host": "localhost" }"#).unwrap_err();
+/// assert!(error.to_string().contains("port must not be zero"));
+/// ```
+impl<'de, T> Deserialize<'de> for Valid<T>
+where
+    T: Deserialize<'de> + for<'a> Validate<Context<'a> = ()>,
+    T::Error: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_valid(deserializer)
+    }
+}
+
+/// Deserialize a `T`, recording the [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) of the
+/// value which caused deserialization to fail
+///
+/// This is only available with the `json-pointer` feature enabled.
+///
+/// If part of the path can't be represented as a JSON Pointer (for example, a map key that isn't a
+/// string or number), the pointer stops at the last segment that could be, and
+/// [`PointerError::truncated`] is set.
+///
+/// Combined with [`deserialize_valid`], this reports which nested field failed validation:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Zip(String);
+///
+/// struct InvalidZip;
+///
+/// impl std::fmt::Display for InvalidZip {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     write!(f, "zip codes must be 5 digits")
+///   }
+/// }
+///
+/// impl Validate for Zip {
+///   type Context<'a> = ();
+///   type Error = InvalidZip;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.len() == 5 && self.0.chars().all(|c| c.is_ascii_digit()) {
+///       true => Ok(()),
+///       false => Err(InvalidZip),
+///     }
+///   }
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Address {
+///   #[serde(deserialize_with = "validity::deserialize_valid")]
+///   zip: Valid<Zip>,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct User {
+///   name: String,
+///   addresses: Vec<Address>,
+/// }
+///
+/// let json = r#"{ "name": "alice", "addresses": [{ "zip": "12345" }, { "zip": "abc" }] }"#;
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let error = deserialize_with_pointer::<_, User>(&mut deserializer).unwrap_err();
+///
+/// assert_eq!(error.pointer, "/addresses/1/zip");
+/// assert!(!error.truncated);
+/// assert!(error.to_string().starts_with("/addresses/1/zip: zip codes must be 5 digits"));
+/// ```
+/// Map keys which aren't strings or numbers (here, `()`) truncate the pointer:
+/// ```
+/// # use validity::*;
+/// # use serde::{de::value::{Error, MapDeserializer}, Deserialize};
+/// # use std::collections::BTreeMap;
+/// #[derive(Debug, Deserialize)]
+/// #[serde(transparent)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err("port 0 is reserved"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let ports = MapDeserializer::<_, Error>::new([((), 0u16)].into_iter());
+/// let error = deserialize_with_pointer::<_, BTreeMap<(), Valid<Port>>>(ports).unwrap_err();
+///
+/// assert_eq!(error.pointer, "");
+/// assert!(error.truncated);
+/// assert_eq!(error.to_string(), "/...: port 0 is reserved");
+/// ```
+#[cfg(feature = "json-pointer")]
+pub fn deserialize_with_pointer<'de, D, T>(deserializer: D) -> Result<T, PointerError<D::Error>>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    use serde_path_to_error::Segment;
+
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let mut pointer = String::new();
+        let mut truncated = false;
+        for segment in error.path().iter() {
+            let token = match segment {
+                Segment::Seq { index } => alloc::format!("{index}"),
+                Segment::Map { key } => key.replace('~', "~0").replace('/', "~1"),
+                Segment::Enum { variant } => variant.replace('~', "~0").replace('/', "~1"),
+                // skipping the segment would point at the wrong value, so stop here instead
+                _ => {
+                    truncated = true;
+                    break;
+                }
+            };
+            // writing to a `String` can't fail
+            let _ = write!(pointer, "/{token}");
+        }
+
+        PointerError {
+            pointer,
+            truncated,
+            error: error.into_inner(),
+        }
+    })
+}
+
+/// The error returned by [`deserialize_with_pointer`]
+#[cfg(feature = "json-pointer")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointerError<E> {
+    /// The JSON Pointer of the value which caused the error (empty if it was the root value)
+    pub pointer: String,
+    /// Whether the pointer stops short of the value which caused the error
+    ///
+    /// If this is `true`, the error came from somewhere below `pointer`, but the rest of the path
+    /// couldn't be represented.
+    pub truncated: bool,
+    /// The error that was generated
+    pub error: E,
+}
+
+#[cfg(feature = "json-pointer")]
+impl<E: Display> Display for PointerError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (self.pointer.is_empty(), self.truncated) {
+            (true, false) => write!(f, "{}", self.error),
+            (false, false) => write!(f, "{}: {}", self.pointer, self.error),
+            (_, true) => write!(f, "{}/...: {}", self.pointer, self.error),
+        }
+    }
+}
+
+#[cfg(feature = "json-pointer")]
+impl<E: std::error::Error + 'static> std::error::Error for PointerError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
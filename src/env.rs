@@ -0,0 +1,137 @@
+use core::{cell::Cell, time::Duration};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::SystemTime,
+};
+
+/// The environment a validator runs in: the current time, and a source of randomness
+///
+/// This is only available with the `std` feature enabled.
+///
+/// Validators which call `SystemTime::now()` directly can't be tested deterministically. Instead,
+/// they should take a `ValidationEnv` as (part of) their context, so that tests can inject a
+/// [`TestEnv`] while production code uses [`SystemEnv`]:
+/// ```
+/// # use validity::*;
+/// # use std::time::{Duration, SystemTime};
+/// #[derive(Debug)]
+/// struct Coupon {
+///   expires_at: SystemTime,
+/// }
+///
+/// impl Validate for Coupon {
+///   type Context<'a> = &'a dyn ValidationEnv;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, env: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match env.now() < self.expires_at {
+///       true => Ok(()),
+///       false => Err("coupon has expired"),
+///     }
+///   }
+/// }
+///
+/// let expires_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let coupon = || Coupon { expires_at };
+///
+/// let env = TestEnv::new(expires_at - Duration::from_secs(1));
+/// assert!(coupon().validate_with(&env).is_ok());
+///
+/// // exactly at the boundary, the coupon has expired
+/// env.advance(Duration::from_secs(1));
+/// assert_eq!(coupon().validate_with(&env).unwrap_err().error, "coupon has expired");
+///
+/// // in production, use the real clock
+/// assert!(coupon().validate_with(&SystemEnv).is_err());
+/// ```
+pub trait ValidationEnv {
+    /// The current time
+    fn now(&self) -> SystemTime;
+
+    /// A random `u64`
+    fn random_u64(&self) -> u64;
+}
+
+/// A [`ValidationEnv`] using the system clock and randomly seeded hashing for randomness
+///
+/// This is only available with the `std` feature enabled.
+///
+/// The randomness is not suitable for cryptographic purposes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemEnv;
+
+impl ValidationEnv for SystemEnv {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn random_u64(&self) -> u64 {
+        RandomState::new().build_hasher().finish()
+    }
+}
+
+/// A deterministic [`ValidationEnv`] for tests, with a fixed clock and seeded randomness
+///
+/// This is only available with the `std` feature enabled.
+///
+/// The clock only moves when [`TestEnv::set_now`] or [`TestEnv::advance`] is called. Random
+/// numbers are produced by a simple pseudo-random generator, so the same seed always produces the
+/// same sequence:
+/// ```
+/// # use validity::*;
+/// # use std::time::SystemTime;
+/// let a = TestEnv::new(SystemTime::UNIX_EPOCH).with_seed(42);
+/// let b = TestEnv::new(SystemTime::UNIX_EPOCH).with_seed(42);
+///
+/// assert_eq!(a.random_u64(), b.random_u64());
+/// assert_eq!(a.random_u64(), b.random_u64());
+/// assert_eq!(a.now(), SystemTime::UNIX_EPOCH);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestEnv {
+    now: Cell<SystemTime>,
+    state: Cell<u64>,
+}
+
+impl TestEnv {
+    /// Create an environment whose clock is fixed at `now`
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Cell::new(now),
+            state: Cell::new(0x853c_49e6_748f_ea9b),
+        }
+    }
+
+    /// Seed the random number generator
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.state.set(seed);
+        self
+    }
+
+    /// Set the current time
+    pub fn set_now(&self, now: SystemTime) {
+        self.now.set(now);
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl ValidationEnv for TestEnv {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn random_u64(&self) -> u64 {
+        // splitmix64
+        let state = self.state.get().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        self.state.set(state);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter, Write};
+
+/// An error which can describe itself in multiple languages
+///
+/// Validation logic usually shouldn't care about which language its errors are displayed in. This
+/// trait lets the error type provide translated messages, so that the boundary of an application
+/// (e.g. an HTTP handler) can pick the right one for each request:
+/// ```
+/// # use validity::*;
+/// enum InvalidUsername {
+///   TooShort,
+///   TooLong,
+/// }
+///
+/// impl LocalizedValidationError for InvalidUsername {
+///   fn message_for(&self, lang: &str) -> String {
+///     match (self, lang) {
+///       (Self::TooShort, "fr") => "nom d'utilisateur trop court".to_string(),
+///       (Self::TooLong, "fr") => "nom d'utilisateur trop long".to_string(),
+///       (Self::TooShort, _) => "username too short".to_string(),
+///       (Self::TooLong, _) => "username too long".to_string(),
+///     }
+///   }
+/// }
+///
+/// assert_eq!(InvalidUsername::TooShort.message_for("en"), "username too short");
+/// assert_eq!(InvalidUsername::TooShort.message_for("fr"), "nom d'utilisateur trop court");
+/// assert_eq!(InvalidUsername::TooLong.message_for("fr"), "nom d'utilisateur trop long");
+/// ```
+///
+/// Implementations should fall back to a default language (usually the same text as the `Display`
+/// impl, if there is one) when `lang` isn't recognised, rather than panicking.
+pub trait LocalizedValidationError {
+    /// Get the message for this error in the given language
+    ///
+    /// The format of `lang` is up to the implementation, but a BCP 47 language tag (e.g. `"en"` or
+    /// `"pt-BR"`) is recommended
+    fn message_for(&self, lang: &str) -> String;
+}
+
+/// Options for [`join_errors`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JoinOptions<'a> {
+    /// The separator between errors (defaults to `"; "`)
+    pub separator: &'a str,
+    /// The maximum number of errors to include, or `None` to include all of them (the default)
+    pub max_count: Option<usize>,
+    /// A field name to prefix each error with, or `None` for no prefix (the default)
+    pub field: Option<&'a str>,
+}
+
+impl Default for JoinOptions<'_> {
+    fn default() -> Self {
+        Self {
+            separator: "; ",
+            max_count: None,
+            field: None,
+        }
+    }
+}
+
+/// Join multiple errors into a single human-readable message
+///
+/// If there are more than `max_count` errors, the rest are summarized as "and N more":
+/// ```
+/// # use validity::*;
+/// let errors = ["too short", "no digit", "no symbol"];
+///
+/// assert_eq!(join_errors(&errors, JoinOptions::default()), "too short; no digit; no symbol");
+///
+/// let options = JoinOptions { separator: ", ", max_count: Some(2), field: Some("password") };
+/// assert_eq!(
+///   join_errors(&errors, options),
+///   "password: too short, password: no digit and 1 more",
+/// );
+/// ```
+pub fn join_errors<E: Display>(errors: &[E], options: JoinOptions<'_>) -> String {
+    let shown = options.max_count.unwrap_or(errors.len()).min(errors.len());
+    let mut output = String::new();
+
+    for (index, error) in errors[..shown].iter().enumerate() {
+        if index > 0 {
+            output.push_str(options.separator);
+        }
+
+        // writing to a `String` can't fail
+        let _ = match options.field {
+            Some(field) => write!(output, "{field}: {error}"),
+            None => write!(output, "{error}"),
+        };
+    }
+
+    match (shown, errors.len() - shown) {
+        (_, 0) => {}
+        (0, hidden) => {
+            let _ = write!(output, "{hidden} more");
+        }
+        (_, hidden) => {
+            let _ = write!(output, " and {hidden} more");
+        }
+    }
+
+    output
+}
+
+/// An error with separate messages for external clients and for internal logs
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// Detailed validation errors can reveal internal rules (for example, which usernames are
+/// reserved), so the message shown to a client should often be less specific than the one that is
+/// logged. Wrapping an error in [`Redacted`] picks the right message for each sink.
+pub trait RedactableError {
+    /// A message which is safe to show to external clients
+    fn public_message(&self) -> String;
+
+    /// A message with full detail, for logs
+    fn internal_message(&self) -> String;
+}
+
+/// A wrapper which displays the [public message](RedactableError::public_message) of an error,
+/// and debug-formats its [internal message](RedactableError::internal_message)
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// Responses are typically built from an error's `Display` impl, and logs from its `Debug` impl,
+/// so using `Redacted<E>` as a `Validate::Error` keeps the detail out of responses. In
+/// particular, the `actix` extractors respond with the public message, and leave the internal
+/// message to actix's error logging:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// enum InvalidUsername {
+///   Reserved(String),
+/// }
+///
+/// impl RedactableError for InvalidUsername {
+///   fn public_message(&self) -> String {
+///     "username is not available".to_string()
+///   }
+///
+///   fn internal_message(&self) -> String {
+///     match self {
+///       Self::Reserved(name) => format!("`{name}` is on the reserved list"),
+///     }
+///   }
+/// }
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = Redacted<InvalidUsername>;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.as_str() {
+///       "admin" | "root" => Err(Redacted(InvalidUsername::Reserved(self.0.clone()))),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let error = Username("admin".to_string()).validate().unwrap_err().error;
+///
+/// let response = error.to_string();
+/// let log = format!("{error:?}");
+/// assert_eq!(response, "username is not available");
+/// assert_eq!(log, "`admin` is on the reserved list");
+/// assert_ne!(response, log);
+/// ```
+pub struct Redacted<E>(pub E);
+
+impl<E> Redacted<E> {
+    /// Get the underlying error
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: RedactableError> Display for Redacted<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0.public_message())
+    }
+}
+
+impl<E: RedactableError> Debug for Redacted<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0.internal_message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: RedactableError> std::error::Error for Redacted<E> {}
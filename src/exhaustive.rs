@@ -0,0 +1,78 @@
+/// Define an error enum with a `handle` method that takes one closure per variant
+///
+/// `match` is already exhaustive, but a `_` arm (or a helper which only handles some variants)
+/// silently swallows variants added later. `handle` takes a closure for every variant, in
+/// declaration order, so adding a variant is a compile error at every call site until it is
+/// handled. The closure parameters are named after the variants. Only unit variants are
+/// supported:
+/// ```
+/// # use validity::*;
+/// exhaustive_error! {
+///   #[derive(Debug, PartialEq)]
+///   pub enum InvalidPhoneNumber {
+///     NonDigit,
+///     WrongLength,
+///   }
+/// }
+///
+/// #[derive(Debug)]
+/// struct PhoneNumber(String);
+///
+/// impl Validate for PhoneNumber {
+///   type Context<'a> = ();
+///   type Error = InvalidPhoneNumber;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.0.len() != 11 {
+///       return Err(InvalidPhoneNumber::WrongLength);
+///     }
+///     if !self.0.chars().all(|c| c.is_ascii_digit()) {
+///       return Err(InvalidPhoneNumber::NonDigit);
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// let error = PhoneNumber("0123".to_string()).validate().unwrap_err().error;
+/// let message = error.handle(|| "digits only, please", || "must be 11 digits long");
+/// assert_eq!(message, "must be 11 digits long");
+/// ```
+/// Forgetting a variant fails to compile:
+/// ```compile_fail
+/// # use validity::*;
+/// exhaustive_error! {
+///   pub enum InvalidPhoneNumber {
+///     NonDigit,
+///     WrongLength,
+///     UnknownCountry,
+///   }
+/// }
+///
+/// let message = InvalidPhoneNumber::NonDigit.handle(|| "digits only", || "wrong length");
+/// ```
+#[macro_export]
+macro_rules! exhaustive_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl $name {
+            /// Handle this error by calling the closure for its variant
+            ///
+            /// There is one closure for each variant, in declaration order.
+            #[allow(non_snake_case, clippy::too_many_arguments)]
+            $vis fn handle<R>(self, $($variant: impl ::core::ops::FnOnce() -> R),*) -> R {
+                match self {
+                    $(Self::$variant => $variant()),*
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,90 @@
+use alloc::boxed::Box;
+
+use crate::Valid;
+
+/// Helpers for passing valid values across an FFI boundary
+///
+/// These are only available with the `ffi` feature enabled.
+///
+/// A value which is exported to foreign code and later handed back loses its proof of validity,
+/// since the compiler can't see what happened to it in between. If the value is known to have come
+/// from one of these exports and to be unchanged, it can be re-wrapped without validating it again:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(()),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// extern "C" fn port_new(port: u16) -> *mut Port {
+///   match Port(port).validate() {
+///     Ok(port) => port.into_raw(),
+///     Err(_) => std::ptr::null_mut(),
+///   }
+/// }
+///
+/// /// # Safety
+/// ///
+/// /// `port` must have been returned by `port_new`, and not freed
+/// unsafe extern "C" fn port_number(port: *const Port) -> u16 {
+///   // SAFETY: the caller guarantees that `port` points to a live `Port`
+///   unsafe { (*port).0 }
+/// }
+///
+/// /// # Safety
+/// ///
+/// /// `port` must have been returned by `port_new`, and not freed
+/// unsafe extern "C" fn port_free(port: *mut Port) {
+///   // SAFETY: `port` came from `Valid::into_raw`, and foreign code can't modify a `Port`
+///   let port: Valid<Port> = unsafe { Valid::from_raw(port) };
+///   assert_eq!(port.0, 8080);
+/// }
+///
+/// let handle = port_new(8080);
+/// assert_eq!(unsafe { port_number(handle) }, 8080);
+/// unsafe { port_free(handle) };
+///
+/// assert!(port_new(0).is_null());
+/// ```
+impl<T> Valid<T> {
+    /// Get a pointer to the inner value
+    ///
+    /// The pointer is valid for as long as `self` is, and must not be used to mutate the value
+    pub fn as_ptr(&self) -> *const T {
+        &self.0
+    }
+
+    /// Move the inner value to the heap, and return a pointer to it
+    ///
+    /// The pointer should eventually be passed to [`Valid::from_raw`] to free it
+    pub fn into_raw(self) -> *mut T {
+        Box::into_raw(Box::new(self.0))
+    }
+
+    /// Take ownership of a value previously exported with [`Valid::into_raw`], without validating
+    /// it
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by `Valid::<T>::into_raw` (i.e. it originated from a
+    /// `Valid<T>` of the same type), and must not have been passed to this function already.
+    ///
+    /// The value must not have been modified since it was exported. Anything done to it in the
+    /// meantime (by foreign code or otherwise) is not re-checked, so a modified value would break
+    /// the guarantee that every `Valid<T>` is valid. If the value may have been modified, use
+    /// `Box::from_raw` and validate it again instead.
+    pub unsafe fn from_raw(ptr: *mut T) -> Self {
+        // SAFETY: the caller guarantees that `ptr` came from `Box::into_raw` in `into_raw`
+        Self(*unsafe { Box::from_raw(ptr) })
+    }
+}
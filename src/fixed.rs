@@ -0,0 +1,62 @@
+use arrayvec::ArrayVec;
+
+use crate::{ValidCollection, Validate};
+
+/// An `ArrayVec<T, CAP>` is valid if every element is valid
+///
+/// This is only available with the `arrayvec` feature enabled.
+///
+/// This mirrors the `Validate` impl for `Vec<T>`, but doesn't allocate, so it is usable without
+/// the `alloc` feature. The context is cloned for each element, and the error contains the index
+/// of the first invalid element:
+/// ```
+/// # use validity::*;
+/// # use arrayvec::ArrayVec;
+/// #[derive(Debug)]
+/// struct Reading(i16);
+///
+/// impl Validate for Reading {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       -40..=125 => Ok(()),
+///       _ => Err("outside the sensor's range"),
+///     }
+///   }
+/// }
+///
+/// let readings: ArrayVec<Reading, 4> = [Reading(20), Reading(21), Reading(-5)].into_iter().collect();
+/// let readings = readings.validate().unwrap();
+/// assert_eq!(readings.len(), 3);
+///
+/// let readings: ArrayVec<Reading, 4> =
+///   [Reading(20), Reading(-100), Reading(22), Reading(500)].into_iter().collect();
+/// let failure = readings.validate().unwrap_err();
+/// assert_eq!(failure.error, (1, "outside the sensor's range"));
+///
+/// // like `Vec<T>`, it can be collected from valid elements without validating again
+/// let readings: Valid<ArrayVec<Reading, 4>> = [Reading(1), Reading(2)]
+///   .into_iter()
+///   .map(|reading| reading.validate().unwrap())
+///   .collect();
+/// assert_eq!(readings.len(), 2);
+/// ```
+impl<T, const CAP: usize> Validate for ArrayVec<T, CAP>
+where
+    T: Validate,
+    for<'a> T::Context<'a>: Clone,
+{
+    type Context<'a> = T::Context<'a>;
+    type Error = (usize, T::Error);
+
+    fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        self.iter()
+            .enumerate()
+            .try_for_each(|(index, item)| item.is_valid(ctx.clone()).map_err(|e| (index, e)))
+    }
+}
+
+/// Collecting more than `CAP` elements panics, as it does for `ArrayVec` itself
+impl<T, const CAP: usize> ValidCollection<T> for ArrayVec<T, CAP> {}
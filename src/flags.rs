@@ -0,0 +1,57 @@
+use bitflags::Flags;
+
+use crate::Valid;
+
+/// Forwarding accessors for validated flag sets
+///
+/// This is only available with the `bitflags` feature enabled.
+///
+/// Some combinations of flags may be illegal (e.g. two mutually exclusive flags), which can be
+/// checked in `is_valid`. These methods query a `Valid<T>` without dereferencing it:
+/// ```
+/// # use validity::*;
+/// bitflags::bitflags! {
+///   #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///   struct Access: u8 {
+///     const READ_ONLY = 1 << 0;
+///     const READ_WRITE = 1 << 1;
+///     const APPEND = 1 << 2;
+///   }
+/// }
+///
+/// impl Validate for Access {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.contains(Access::READ_ONLY) && self.intersects(Access::READ_WRITE | Access::APPEND) {
+///       return Err("read-only access can't be combined with writes");
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// let access = (Access::READ_WRITE | Access::APPEND).validate().unwrap();
+/// assert!(access.contains(Access::APPEND));
+/// assert!(!access.intersects(Access::READ_ONLY));
+/// assert_eq!(access.bits(), 0b110);
+///
+/// let failure = (Access::READ_ONLY | Access::APPEND).validate().unwrap_err();
+/// assert_eq!(failure.error, "read-only access can't be combined with writes");
+/// ```
+impl<T: Flags> Valid<T> {
+    /// Whether all flags in `other` are set
+    pub fn contains(&self, other: T) -> bool {
+        self.0.contains(other)
+    }
+
+    /// Whether any flags in `other` are set
+    pub fn intersects(&self, other: T) -> bool {
+        self.0.intersects(other)
+    }
+
+    /// The underlying bits of the flag set
+    pub fn bits(&self) -> T::Bits {
+        self.0.bits()
+    }
+}
@@ -0,0 +1,96 @@
+use core::{
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::Valid;
+
+/// Hashing semantics for a validated value, distinct from its own [`Hash`] impl
+///
+/// `Valid<T>` always hashes exactly like `T`, since it implements `Borrow<T>` and `HashMap`
+/// requires the two to agree. When hashing should ignore part of a value (e.g. a cached
+/// computation), implement this trait and wrap the value with [`Valid::hash_by_valid_hash`]:
+/// ```
+/// # use validity::*;
+/// # use std::hash::{Hash, Hasher};
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Query {
+///   text: String,
+///   cached_len: usize,
+/// }
+///
+/// impl Validate for Query {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.text.is_empty() {
+///       true => Err(()),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// impl ValidHash for Query {
+///   fn valid_hash<H: Hasher>(&self, state: &mut H) {
+///     self.text.hash(state);
+///   }
+/// }
+///
+/// # use std::{collections::hash_map::RandomState, hash::BuildHasher};
+/// # let state = RandomState::new();
+/// let a = Query { text: "rust".into(), cached_len: 4 }.validate().unwrap();
+/// let b = Query { text: "rust".into(), cached_len: 0 }.validate().unwrap();
+///
+/// // `Valid<Query>` hashes like `Query`, including `cached_len`
+/// assert_ne!(state.hash_one(&a), state.hash_one(&b));
+///
+/// // `HashedValid<Query>` uses `ValidHash`, ignoring it
+/// let a = a.hash_by_valid_hash();
+/// let b = b.hash_by_valid_hash();
+/// assert_eq!(state.hash_one(&a), state.hash_one(&b));
+/// ```
+pub trait ValidHash {
+    /// Feed this value into the given [`Hasher`]
+    ///
+    /// Like [`Hash::hash`], values which compare equal must produce the same hash.
+    fn valid_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// A `Valid<T>` which hashes using [`ValidHash`] rather than [`Hash`]
+///
+/// Created by [`Valid::hash_by_valid_hash`]. Unlike `Valid<T>`, this does not implement
+/// `Borrow<T>`, since the hashes of the two can differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HashedValid<T>(Valid<T>);
+
+impl<T> Valid<T> {
+    /// Wrap this value so that it hashes using [`ValidHash`] rather than `T`'s [`Hash`] impl
+    pub fn hash_by_valid_hash(self) -> HashedValid<T>
+    where
+        T: ValidHash,
+    {
+        HashedValid(self)
+    }
+}
+
+impl<T> HashedValid<T> {
+    /// Consume self and return the inner `Valid<T>`
+    pub fn into_inner(self) -> Valid<T> {
+        self.0
+    }
+}
+
+impl<T: ValidHash> Hash for HashedValid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0 .0.valid_hash(state);
+    }
+}
+
+impl<T> Deref for HashedValid<T> {
+    type Target = Valid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::{self, Read, Write};
+
+use crate::Valid;
+
+/// A marker for types whose [`Read`] and [`Write`] impls cannot break their validity
+///
+/// Reading and writing both take `&mut self`, so forwarding them through `Valid<T>` would allow
+/// the inner value to be mutated without being re-validated. Implementing this trait is a promise
+/// that no sequence of reads, writes or flushes can turn a valid value into an invalid one, which
+/// allows `Valid<T>` to implement `Read` and `Write` when `T` does.
+///
+/// For example, a buffer with a maximum length is safe to write to, as long as writes past the
+/// maximum length are rejected rather than growing the buffer:
+/// ```
+/// # use validity::*;
+/// # use std::io::Write;
+/// #[derive(Debug)]
+/// struct BoundedBuffer {
+///   data: Vec<u8>,
+///   max_len: usize,
+/// }
+///
+/// impl Validate for BoundedBuffer {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.data.len() <= self.max_len {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// impl Write for BoundedBuffer {
+///   fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+///     let remaining = self.max_len - self.data.len();
+///     let len = buf.len().min(remaining);
+///     self.data.extend_from_slice(&buf[..len]);
+///     Ok(len)
+///   }
+///
+///   fn flush(&mut self) -> std::io::Result<()> {
+///     Ok(())
+///   }
+/// }
+///
+/// // writes can never grow the buffer past `max_len`
+/// impl ValidIo for BoundedBuffer {}
+///
+/// let mut buffer = BoundedBuffer { data: vec![], max_len: 4 }.validate().unwrap();
+///
+/// write!(buffer, "hi").unwrap();
+/// assert!(write!(buffer, "hello").is_err());  // `write_all` fails once the buffer is full
+///
+/// assert_eq!(buffer.data, b"hihe");
+/// assert!(buffer.into_inner().validate().is_ok());
+/// ```
+///
+/// If the IO impl can break the invariant, don't implement this trait. Instead, take the value out
+/// with [`Valid::into_inner`], do the IO, and validate it again.
+pub trait ValidIo {}
+
+impl<T: Read + ValidIo> Read for Valid<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<T: Write + ValidIo> Write for Valid<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
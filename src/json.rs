@@ -0,0 +1,242 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{Valid, Validate};
+
+/// Deserialize a `T` from a [`serde_json::Value`], and then validate it with the given context
+///
+/// This is only available with the `json` feature enabled.
+///
+/// This is useful when a payload has already been parsed into a `Value` by an earlier, generic
+/// stage. The error distinguishes values of the wrong shape from values which fail validation:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// #[derive(Debug, Deserialize)]
+/// struct Signup {
+///   email: String,
+///   age: u8,
+/// }
+///
+/// impl Validate for Signup {
+///   type Context<'a> = u8;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, min_age: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.age >= min_age {
+///       true => Ok(()),
+///       false => Err("too young"),
+///     }
+///   }
+/// }
+///
+/// let signup = from_json_value::<Signup>(json!({ "email": "a@example.com", "age": 30 }), 18).unwrap();
+/// assert_eq!(signup.age, 30);
+///
+/// let error = from_json_value::<Signup>(json!({ "email": "a@example.com" }), 18).unwrap_err();
+/// assert!(matches!(error, FromValueError::Deserialize(_)));
+///
+/// let error = from_json_value::<Signup>(json!({ "email": "a@example.com", "age": 12 }), 18).unwrap_err();
+/// assert!(matches!(error, FromValueError::Invalid("too young")));
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn from_json_value<T>(
+    value: Value,
+    ctx: T::Context<'_>,
+) -> Result<Valid<T>, FromValueError<T::Error>>
+where
+    T: DeserializeOwned + Validate,
+{
+    serde_json::from_value::<T>(value)
+        .map_err(FromValueError::Deserialize)?
+        .validate_with(ctx)
+        .map_err(|failure| FromValueError::Invalid(failure.error))
+}
+
+/// The error returned by [`from_json_value`]
+///
+/// This is only available with the `json` feature enabled.
+pub enum FromValueError<E> {
+    /// The value could not be deserialized into the target type
+    Deserialize(serde_json::Error),
+    /// The value was deserialized, but failed validation
+    Invalid(E),
+}
+
+impl<E: Debug> Debug for FromValueError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Deserialize(error) => f.debug_tuple("Deserialize").field(error).finish(),
+            Self::Invalid(error) => f.debug_tuple("Invalid").field(error).finish(),
+        }
+    }
+}
+
+impl<E: Display> Display for FromValueError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Deserialize(error) => write!(f, "failed to deserialize: {error}"),
+            Self::Invalid(error) => write!(f, "validation failed: {error}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for FromValueError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Deserialize(error) => Some(error),
+            Self::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl<T> Valid<T>
+where
+    T: Serialize + DeserializeOwned + Validate,
+{
+    /// Apply a [JSON Merge Patch](https://www.rfc-editor.org/rfc/rfc7396) to this value, and
+    /// validate the result with the given context
+    ///
+    /// This is only available with the `json` feature enabled.
+    ///
+    /// The value is converted to JSON, patched, converted back, then validated. If any step fails,
+    /// the original (still valid) value is returned along with the error, so a rejected update
+    /// doesn't lose the current state:
+    /// ```
+    /// # use validity::*;
+    /// # use serde::{Deserialize, Serialize};
+    /// # use serde_json::json;
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Profile {
+    ///   name: String,
+    ///   bio: Option<String>,
+    ///   age: u8,
+    /// }
+    ///
+    /// impl Validate for Profile {
+    ///   type Context<'a> = u8;
+    ///   type Error = &'static str;
+    ///
+    ///   fn is_valid(&self, min_age: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.age >= min_age {
+    ///       true => Ok(()),
+    ///       false => Err("too young"),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let profile = Profile { name: "alice".into(), bio: Some("hi".into()), age: 30 };
+    /// let profile = profile.validate_with(18).unwrap();
+    ///
+    /// // `null` removes a field, and everything not mentioned is unchanged
+    /// let profile = profile.apply_merge_patch(json!({ "bio": null, "age": 31 }), 18).unwrap();
+    /// assert_eq!(profile.name, "alice");
+    /// assert_eq!(profile.bio, None);
+    /// assert_eq!(profile.age, 31);
+    ///
+    /// // an invalid result is rejected, and the original value is kept
+    /// let failure = profile.apply_merge_patch(json!({ "age": 12 }), 18).unwrap_err();
+    /// assert!(matches!(failure.error, FromValueError::Invalid("too young")));
+    /// assert_eq!(failure.original.age, 31);
+    ///
+    /// // as is a patch which doesn't fit the type
+    /// let failure = failure.original.apply_merge_patch(json!({ "age": "old" }), 18).unwrap_err();
+    /// assert!(matches!(failure.error, FromValueError::Deserialize(_)));
+    /// assert_eq!(failure.original.age, 31);
+    /// ```
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn apply_merge_patch(
+        self,
+        patch: Value,
+        ctx: T::Context<'_>,
+    ) -> Result<Valid<T>, PatchFailure<T>> {
+        let mut value = match serde_json::to_value(&self.0) {
+            Ok(value) => value,
+            Err(error) => return Err(self.reject(FromValueError::Deserialize(error))),
+        };
+
+        merge_patch(&mut value, patch);
+
+        from_json_value(value, ctx).map_err(|error| self.reject(error))
+    }
+
+    fn reject(self, error: FromValueError<T::Error>) -> PatchFailure<T> {
+        PatchFailure {
+            original: self,
+            error,
+        }
+    }
+}
+
+/// Apply `patch` to `target`, as described in RFC 7396
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    let Value::Object(target) = target else {
+        unreachable!("target was just replaced with an object");
+    };
+
+    for (key, value) in patch {
+        match value {
+            Value::Null => {
+                target.remove(&key);
+            }
+            value => merge_patch(target.entry(key).or_insert(Value::Null), value),
+        }
+    }
+}
+
+/// The error returned by [`Valid::apply_merge_patch`]
+///
+/// This is only available with the `json` feature enabled.
+pub struct PatchFailure<T: Validate> {
+    /// The value before the patch was applied, which is still valid
+    pub original: Valid<T>,
+    /// Why the patched value was rejected
+    ///
+    /// [`FromValueError::Deserialize`] is also used if the original value can't be converted to
+    /// JSON.
+    pub error: FromValueError<T::Error>,
+}
+
+impl<T> Debug for PatchFailure<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PatchFailure")
+            .field("original", &self.original)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<T: Validate> Display for PatchFailure<T>
+where
+    T::Error: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "failed to apply merge patch: {}", self.error)
+    }
+}
+
+impl<T> std::error::Error for PatchFailure<T>
+where
+    T: Validate + Debug,
+    T::Error: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
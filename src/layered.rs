@@ -0,0 +1,83 @@
+/// A context made of a cheap, request-scoped override layered on top of a base context
+///
+/// Building a full context can be expensive (for example, application-wide configuration loaded at
+/// startup). A `LayeredContext` lets a small set of overrides be applied to it without rebuilding
+/// or cloning the base. Typically, both layers are borrowed:
+/// ```
+/// # use validity::*;
+/// struct Config {
+///   max_upload_size: usize,
+///   allowed_types: Vec<&'static str>,
+/// }
+///
+/// #[derive(Default)]
+/// struct Overrides {
+///   max_upload_size: Option<usize>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Upload {
+///   size: usize,
+///   mime_type: &'static str,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidUpload {
+///   TooLarge,
+///   WrongType,
+/// }
+///
+/// impl Validate for Upload {
+///   type Context<'a> = LayeredContext<&'a Config, &'a Overrides>;
+///   type Error = InvalidUpload;
+///
+///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     let max_size = ctx.resolve(|o| o.max_upload_size, |b| b.max_upload_size);
+///     if self.size > max_size {
+///       return Err(InvalidUpload::TooLarge);
+///     }
+///
+///     if !ctx.base.allowed_types.contains(&self.mime_type) {
+///       return Err(InvalidUpload::WrongType);
+///     }
+///
+///     Ok(())
+///   }
+/// }
+///
+/// let config = Config { max_upload_size: 1024, allowed_types: vec!["image/png"] };
+/// let upload = || Upload { size: 4096, mime_type: "image/png" };
+///
+/// // with no overrides, the base config is used
+/// let no_overrides = Overrides::default();
+/// let failure = upload().validate_with(LayeredContext::new(&config, &no_overrides)).unwrap_err();
+/// assert_eq!(failure.error, InvalidUpload::TooLarge);
+///
+/// // the override wins over the base
+/// let overrides = Overrides { max_upload_size: Some(8192) };
+/// assert!(upload().validate_with(LayeredContext::new(&config, &overrides)).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayeredContext<B, O> {
+    /// The base context
+    pub base: B,
+    /// The overrides, which take priority over the base context
+    pub overrides: O,
+}
+
+impl<B, O> LayeredContext<B, O> {
+    /// Layer `overrides` on top of `base`
+    pub fn new(base: B, overrides: O) -> Self {
+        Self { base, overrides }
+    }
+
+    /// Look up a value, using the override if it is present, and falling back to the base
+    /// otherwise
+    pub fn resolve<V>(
+        &self,
+        from_overrides: impl FnOnce(&O) -> Option<V>,
+        from_base: impl FnOnce(&B) -> V,
+    ) -> V {
+        from_overrides(&self.overrides).unwrap_or_else(|| from_base(&self.base))
+    }
+}
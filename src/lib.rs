@@ -120,12 +120,196 @@
 //! let phone = PhoneNumber("01234567890".to_string());
 //! phone.validate_with(db);
 //! ```
+//!
+//! Types with const generic parameters work the same way, so an invariant can depend on a const
+//! generic. No extra bounds are needed, since the const parameter is part of the type that
+//! implements `Validate`:
+//! ```
+//! # use validity::*;
+//! #[derive(Debug)]
+//! struct FixedVec<T, const N: usize>(Vec<T>);
+//!
+//! #[derive(Debug)]
+//! struct TooLong {
+//!   len: usize,
+//!   max: usize,
+//! }
+//!
+//! impl<T, const N: usize> Validate for FixedVec<T, N> {
+//!   type Context<'a> = ();
+//!   type Error = TooLong;
+//!
+//!   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+//!     match self.0.len() <= N {
+//!       true => Ok(()),
+//!       false => Err(TooLong { len: self.0.len(), max: N }),
+//!     }
+//!   }
+//! }
+//!
+//! fn checksum(bytes: Valid<FixedVec<u8, 16>>) -> u8 {
+//!   bytes.0.iter().fold(0, |acc, b| acc ^ b)
+//! }
+//!
+//! let bytes: Valid<FixedVec<u8, 16>> = FixedVec(vec![1, 2, 3]).validate().unwrap();
+//! assert_eq!(checksum(bytes), 0);
+//!
+//! let failure = FixedVec::<u8, 16>(vec![0; 17]).validate().unwrap_err();
+//! assert_eq!(failure.error.len, 17);
+//! assert_eq!(failure.error.max, 16);
+//! ```
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+mod audit;
+#[cfg(feature = "bench-support")]
+mod bench;
+mod both;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "std-validators")]
+pub mod builtin;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod checklist;
+#[cfg(feature = "checkpoints")]
+mod checkpoint;
+mod collections;
+mod compose;
+mod conditional;
+#[cfg(feature = "alloc")]
+mod describe;
+#[cfg(feature = "serde")]
+mod deserialize;
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "alloc")]
+mod error;
+mod exhaustive;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "arrayvec")]
+mod fixed;
+#[cfg(feature = "bitflags")]
+mod flags;
+mod hash;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "json")]
+mod json;
+mod layered;
+mod limits;
+mod normalize;
+#[cfg(feature = "nom")]
+mod parse;
+mod pipeline;
+mod proof;
+mod range;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "alloc")]
+mod repair;
+mod result;
+#[cfg(feature = "alloc")]
+mod scored;
+#[cfg(feature = "serde")]
+mod serialize;
+mod span;
+#[cfg(feature = "json-stream")]
+mod stream;
+mod strictness;
+#[cfg(feature = "test-support")]
+mod testing;
+mod text;
+mod typestate;
+#[cfg(feature = "url")]
+mod url;
+mod versioned;
+#[cfg(feature = "alloc")]
+mod writer;
+
+#[cfg(feature = "actix")]
+pub use actix::*;
+#[cfg(feature = "proptest")]
+pub use arbitrary::*;
+#[cfg(feature = "alloc")]
+pub use audit::*;
+#[cfg(feature = "bench-support")]
+pub use bench::*;
+pub use both::*;
+#[cfg(feature = "alloc")]
+pub use builder::*;
+#[cfg(feature = "bytemuck")]
+pub use bytes::*;
+#[cfg(feature = "std")]
+pub use cache::*;
+#[cfg(feature = "alloc")]
+pub use checklist::*;
+#[cfg(feature = "checkpoints")]
+pub use checkpoint::*;
+pub use collections::*;
+pub use compose::*;
+pub use conditional::*;
+#[cfg(feature = "alloc")]
+pub use describe::*;
+#[cfg(feature = "serde")]
+pub use deserialize::*;
+#[cfg(feature = "std")]
+pub use env::*;
+#[cfg(feature = "alloc")]
+pub use error::*;
+pub use hash::*;
+#[cfg(feature = "std")]
+pub use io::*;
+#[cfg(feature = "json")]
+pub use json::*;
+pub use layered::*;
+pub use limits::*;
+pub use normalize::*;
+#[cfg(feature = "nom")]
+pub use parse::*;
+pub use pipeline::*;
+pub use proof::*;
+pub use range::*;
+#[cfg(feature = "std")]
+pub use registry::*;
+#[cfg(feature = "alloc")]
+pub use repair::*;
+pub use result::*;
+#[cfg(feature = "alloc")]
+pub use scored::*;
+pub use span::*;
+#[cfg(feature = "json-stream")]
+pub use stream::*;
+pub use strictness::*;
+#[cfg(feature = "test-support")]
+pub use testing::*;
+pub use text::*;
+pub use typestate::*;
+#[cfg(feature = "url")]
+pub use url::*;
+pub use versioned::*;
+#[cfg(feature = "alloc")]
+pub use writer::*;
+
 use core::{
+    borrow::Borrow,
     fmt::{Debug, Formatter},
-    ops::Deref,
+    ops::{Deref, DerefMut},
+    pin::Pin,
 };
 
 /// A thin wrapper around a value that guarantees that it is "valid"
@@ -135,7 +319,49 @@ use core::{
 ///
 /// Note, `Valid<T>` is not `repr(transparent)`, so using `transmute` to forcibly convert is
 /// undefined behaviour.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// `Valid<T>` implements each auto trait (`Send`, `Sync`, `Unpin`, `UnwindSafe` and
+/// `RefUnwindSafe`) exactly when `T` does, since it contains nothing but a `T`:
+/// ```
+/// # use validity::*;
+/// # use static_assertions::{assert_impl_all, assert_not_impl_any};
+/// # use std::{cell::Cell, marker::PhantomPinned, panic::{RefUnwindSafe, UnwindSafe}, rc::Rc};
+/// assert_impl_all!(Valid<u32>: Send, Sync, Unpin, UnwindSafe, RefUnwindSafe);
+///
+/// assert_not_impl_any!(Valid<Rc<u32>>: Send, Sync);
+/// assert_impl_all!(Valid<Cell<u32>>: Send);
+/// assert_not_impl_any!(Valid<Cell<u32>>: Sync, RefUnwindSafe);
+/// assert_not_impl_any!(Valid<PhantomPinned>: Unpin);
+/// assert_not_impl_any!(Valid<&mut u32>: UnwindSafe);
+///
+/// // the same is true of `Unvalidated<T>`
+/// assert_impl_all!(Unvalidated<u32>: Send, Sync, Unpin, UnwindSafe, RefUnwindSafe);
+/// assert_not_impl_any!(Unvalidated<Rc<u32>>: Send, Sync);
+/// assert_not_impl_any!(Unvalidated<PhantomPinned>: Unpin);
+/// ```
+///
+/// `Valid<T>` is guaranteed to hash exactly like `T` (this is required by its `Borrow<T>` impl). To
+/// hash differently at the `Valid` level, see [`ValidHash`].
+///
+/// For primitive types, a `Valid<T>` can be compared with a raw `T` (in both directions), and the
+/// comparisons are the same as `T`'s:
+/// ```
+/// # use validity::*;
+/// let score = Valid::trusted(42u32);
+///
+/// assert!(score < 100);
+/// assert!(score >= 42);
+/// assert!(score == 42);
+/// assert!(100 > score);
+/// assert!(42 <= score);
+/// assert!(42 == score);
+///
+/// assert_eq!(score.partial_cmp(&50), score.partial_cmp(&Valid::trusted(50)));
+/// ```
+/// For your own types, the orphan rules allow implementing `PartialEq<MyType>` and
+/// `PartialOrd<MyType>` for `Valid<MyType>` directly.
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
+#[must_use]
 pub struct Valid<T>(T);
 
 impl<T> Valid<T> {
@@ -154,6 +380,17 @@ impl<T> Valid<T> {
     pub fn danger_new_unvalidated(t: T) -> Self {
         Self(t)
     }
+
+    /// Create a `Valid<T>` without calling [`Validate::is_valid`], for types where every value is
+    /// valid
+    ///
+    /// See [`AlwaysValid`] for more details.
+    pub fn trusted(t: T) -> Self
+    where
+        T: AlwaysValid,
+    {
+        Self(t)
+    }
 }
 
 impl<T> Deref for Valid<T> {
@@ -163,6 +400,691 @@ impl<T> Deref for Valid<T> {
     }
 }
 
+impl<T> Valid<T>
+where
+    T: for<'a> Validate<Context<'a> = ()>,
+{
+    /// Borrow the inner value, first checking that it is still valid
+    ///
+    /// With the `debug-assert-valid` feature enabled, the inner value is validated again in debug
+    /// builds, panicking if it is no longer valid. Otherwise (or when `debug_assertions` are
+    /// disabled), this is the same as [`Valid::as_inner`].
+    ///
+    /// This catches bugs where the inner value is changed through interior mutability (e.g. a
+    /// `Cell`) after it was validated. Since validating requires a context, this is only available
+    /// for types whose context is `()`:
+    /// ```
+    /// # use validity::*;
+    /// # use std::cell::Cell;
+    /// #[derive(Debug)]
+    /// struct Even(Cell<u32>);
+    ///
+    /// impl Validate for Even {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.get() % 2 {
+    ///       0 => Ok(()),
+    ///       _ => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let even = Even(Cell::new(2)).validate().unwrap();
+    /// assert_eq!(even.debug_check().0.get(), 2);
+    ///
+    /// // oops, `even` is no longer valid, so `even.debug_check()` would now panic (with the
+    /// // `debug-assert-valid` feature enabled, in debug builds)
+    /// even.0.set(3);
+    /// ```
+    #[track_caller]
+    pub fn debug_check(&self) -> &T {
+        #[cfg(all(feature = "debug-assert-valid", debug_assertions))]
+        if self.0.is_valid(()).is_err() {
+            let type_name = core::any::type_name::<T>();
+            panic!("`Valid<{type_name}>` contains a value which is no longer valid");
+        }
+
+        &self.0
+    }
+}
+
+/// A `Valid<T>` is always valid, so validating it again does nothing
+///
+/// This means a field which is already a `Valid<T>` can be validated along with the rest of a
+/// struct, without calling `T`'s validator a second time:
+/// ```
+/// # use validity::*;
+/// # use std::cell::Cell;
+/// #[derive(Debug)]
+/// struct Address(String);
+///
+/// impl Validate for Address {
+///   type Context<'a> = &'a Cell<u32>;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, calls: Self::Context<'_>) -> Result<(), Self::Error> {
+///     calls.set(calls.get() + 1);
+///     match self.0.is_empty() {
+///       true => Err("empty address"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Order {
+///   quantity: u32,
+///   address: Valid<Address>,
+/// }
+///
+/// impl Validate for Order {
+///   type Context<'a> = &'a Cell<u32>;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, calls: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.quantity == 0 {
+///       return Err("empty order");
+///     }
+///     self.address.is_valid(calls)
+///   }
+/// }
+///
+/// let calls = Cell::new(0);
+/// let address = Address("1 Main St".into()).validate_with(&calls).unwrap();
+/// assert_eq!(calls.get(), 1);
+///
+/// let order = Order { quantity: 2, address }.validate_with(&calls).unwrap();
+/// assert_eq!(calls.get(), 1);
+/// ```
+impl<T: Validate> Validate for Valid<T> {
+    type Context<'a> = T::Context<'a>;
+    type Error = T::Error;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// `Valid<T>` hashes and compares exactly like `T`, so collections of `Valid<T>` can be queried with
+/// a `&T`
+///
+/// See `ValidSet` for an example.
+impl<T> Borrow<T> for Valid<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Compares the inner values, so valid values of different types can be compared if their inner
+/// types can be:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, PartialEq)]
+/// struct RawEmail(String);
+///
+/// #[derive(Debug)]
+/// struct NormalizedEmail(String);
+///
+/// impl PartialEq<NormalizedEmail> for RawEmail {
+///   fn eq(&self, other: &NormalizedEmail) -> bool {
+///     self.0 == other.0
+///   }
+/// }
+///
+/// impl Validate for RawEmail {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.contains('@') {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// impl Validate for NormalizedEmail {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.contains('@') && self.0 == self.0.to_lowercase() {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// let raw = RawEmail("foo@example.com".to_string()).validate().unwrap();
+/// let normalized = NormalizedEmail("foo@example.com".to_string()).validate().unwrap();
+/// let other = NormalizedEmail("bar@example.com".to_string()).validate().unwrap();
+///
+/// assert_eq!(raw, normalized);
+/// assert_ne!(raw, other);
+///
+/// // comparisons between the same type still work
+/// assert_eq!(raw, RawEmail("foo@example.com".to_string()).validate().unwrap());
+/// ```
+impl<T, U> PartialEq<Valid<U>> for Valid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Valid<U>) -> bool {
+        self.0 == other.0
+    }
+}
+
+// Implements comparisons between `Valid<T>` and a raw `T` (in both directions) for primitive types.
+//
+// A blanket `impl<T> PartialOrd<T> for Valid<T>` would overlap with the comparisons between
+// `Valid<T>` and `Valid<U>` (when `T` is itself a `Valid`), so these are only implemented for
+// concrete types.
+macro_rules! impl_raw_comparisons {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<$t> for Valid<$t> {
+                fn eq(&self, other: &$t) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl PartialEq<Valid<$t>> for $t {
+                fn eq(&self, other: &Valid<$t>) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl PartialOrd<$t> for Valid<$t> {
+                fn partial_cmp(&self, other: &$t) -> Option<core::cmp::Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            impl PartialOrd<Valid<$t>> for $t {
+                fn partial_cmp(&self, other: &Valid<$t>) -> Option<core::cmp::Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_raw_comparisons!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, bool
+);
+
+// every value of a primitive is valid, so these can be used with `Valid::trusted`
+macro_rules! impl_always_valid {
+    ($($t:ty),*) => {
+        $(
+            impl Validate for $t {
+                type Context<'a> = ();
+                type Error = core::convert::Infallible;
+
+                fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+                    Ok(())
+                }
+            }
+
+            impl AlwaysValid for $t {}
+        )*
+    };
+}
+
+impl_always_valid!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, bool
+);
+
+impl<T: Deref> Valid<T> {
+    /// Borrow the target of the inner value, like [`Option::as_deref`]
+    ///
+    /// Method calls already dereference through `Valid<T>` automatically, but generic code (or
+    /// code expecting a `&dyn Trait`) sometimes needs the target explicitly. This is mostly useful
+    /// for validated trait objects:
+    /// ```
+    /// # use validity::*;
+    /// trait Shape: std::fmt::Debug {
+    ///   fn area(&self) -> f64;
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Square(f64);
+    ///
+    /// impl Shape for Square {
+    ///   fn area(&self) -> f64 {
+    ///     self.0 * self.0
+    ///   }
+    /// }
+    ///
+    /// impl Validate for Box<dyn Shape> {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.area() > 0.0 {
+    ///       true => Ok(()),
+    ///       false => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// fn total_area(shapes: &[&dyn Shape]) -> f64 {
+    ///   shapes.iter().map(|s| s.area()).sum()
+    /// }
+    ///
+    /// let shape: Valid<Box<dyn Shape>> = (Box::new(Square(2.0)) as Box<dyn Shape>).validate().unwrap();
+    ///
+    /// // methods are called through both `Valid` and `Box`
+    /// assert_eq!(shape.area(), 4.0);
+    ///
+    /// // `as_deref` gives a `&dyn Shape` directly
+    /// assert_eq!(total_area(&[shape.as_deref(), shape.as_deref()]), 8.0);
+    /// ```
+    pub fn as_deref(&self) -> &T::Target {
+        &self.0
+    }
+}
+
+impl<T> Valid<T> {
+    /// Give up the proof of validity, so that the inner value can be mutated
+    ///
+    /// This makes breaking the invariant visible in the types: the only way to get a `Valid<T>`
+    /// back is to validate the [`Unvalidated<T>`] again:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Even(u32);
+    ///
+    /// impl Validate for Even {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 % 2 {
+    ///       0 => Ok(()),
+    ///       _ => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let even = Even(2).validate().unwrap();
+    ///
+    /// let mut even = even.downgrade();
+    /// even.0 += 1;
+    /// let failure = even.validate().unwrap_err();
+    /// assert_eq!(failure.value.0, 3);
+    ///
+    /// let mut even = Unvalidated::new(failure.value);
+    /// even.0 += 1;
+    /// assert_eq!(even.validate().unwrap().0, 4);
+    /// ```
+    pub fn downgrade(self) -> Unvalidated<T> {
+        Unvalidated(self.0)
+    }
+}
+
+impl<T> Valid<T> {
+    /// Borrow the inner value
+    ///
+    /// This is equivalent to `&*valid`, but reads more clearly as the scrutinee of a `match`:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// enum Payment {
+    ///   Card { last_four: String },
+    ///   Transfer { iban: String },
+    ///   Cash,
+    /// }
+    ///
+    /// impl Validate for Payment {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self {
+    ///       Self::Card { last_four } if last_four.len() != 4 => Err(()),
+    ///       Self::Transfer { iban } if iban.len() < 15 => Err(()),
+    ///       _ => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let payment = Payment::Card { last_four: "1234".into() }.validate().unwrap();
+    ///
+    /// match payment.as_inner() {
+    ///   Payment::Card { last_four } => assert_eq!(last_four, "1234"),
+    ///   Payment::Transfer { .. } | Payment::Cash => unreachable!(),
+    /// }
+    ///
+    /// let label = payment.map_ref_to(|payment| match payment {
+    ///   Payment::Card { .. } => "card",
+    ///   Payment::Transfer { .. } => "transfer",
+    ///   Payment::Cash => "cash",
+    /// });
+    /// assert_eq!(label, "card");
+    /// ```
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Compute a value from a reference to the inner value
+    ///
+    /// The result is not wrapped in `Valid`, since `f` may not preserve validity.
+    pub fn map_ref_to<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        f(&self.0)
+    }
+
+    /// Consume self and return the inner value, or `fallback` if `pred` returns `true` for it
+    ///
+    /// This is useful for business rules applied after validation:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Discount(u8);
+    ///
+    /// impl Validate for Discount {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 <= 100 {
+    ///       true => Ok(()),
+    ///       false => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// // guests are limited to 20% off
+    /// let is_too_generous = |discount: &Discount| discount.0 > 20;
+    ///
+    /// let discount = Discount(50).validate().unwrap();
+    /// assert_eq!(discount.unwrap_or_else_if(is_too_generous, Discount(20)), Discount(20));
+    ///
+    /// let discount = Discount(10).validate().unwrap();
+    /// assert_eq!(discount.unwrap_or_else_if(is_too_generous, Discount(20)), Discount(10));
+    /// ```
+    pub fn unwrap_or_else_if(self, pred: impl FnOnce(&T) -> bool, fallback: T) -> T {
+        match pred(&self.0) {
+            true => fallback,
+            false => self.0,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Valid<T> {
+    /// Borrow the inner value as a [`Cow::Borrowed`](alloc::borrow::Cow::Borrowed)
+    pub fn as_cow(&self) -> alloc::borrow::Cow<'_, T> {
+        alloc::borrow::Cow::Borrowed(&self.0)
+    }
+
+    /// Consume self and return the inner value as a [`Cow::Owned`](alloc::borrow::Cow::Owned)
+    ///
+    /// Together with [`Valid::as_cow`], this allows code which is generic over ownership to handle
+    /// owned and borrowed valid values the same way:
+    /// ```
+    /// # use validity::*;
+    /// # use std::borrow::Cow;
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Name(String);
+    ///
+    /// impl Validate for Name {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.is_empty() {
+    ///       true => Err(()),
+    ///       false => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let name = Name("alice".to_string()).validate().unwrap();
+    ///
+    /// let borrowed = name.as_cow();
+    /// assert!(matches!(borrowed, Cow::Borrowed(_)));
+    ///
+    /// let owned: Cow<'static, Name> = name.into_cow();
+    /// assert!(matches!(owned, Cow::Owned(_)));
+    /// assert_eq!(owned.into_owned(), Name("alice".to_string()));
+    /// ```
+    pub fn into_cow(self) -> alloc::borrow::Cow<'static, T>
+    where
+        T: 'static,
+    {
+        alloc::borrow::Cow::Owned(self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Valid<&T>
+where
+    T: alloc::borrow::ToOwned + ?Sized,
+    T::Owned: ValidToOwned,
+{
+    /// Convert a valid borrowed value into a valid owned value, without validating again
+    ///
+    /// This is only available with the `alloc` feature enabled.
+    ///
+    /// The owned type must implement [`ValidToOwned`], promising that it is valid whenever its
+    /// borrowed form is (for example, because its `Validate` impl delegates to `&T`'s, as below).
+    /// This is typically used with borrowed/owned pairs like `str`/`String`:
+    /// ```
+    /// # use validity::*;
+    /// # use std::borrow::Borrow;
+    /// #[derive(Debug)]
+    /// #[repr(transparent)]
+    /// struct Slug(str);
+    ///
+    /// #[derive(Debug)]
+    /// struct SlugBuf(String);
+    ///
+    /// impl Slug {
+    ///   fn new(s: &str) -> &Slug {
+    ///     // SAFETY: `Slug` is `repr(transparent)` over `str`
+    ///     unsafe { &*(s as *const str as *const Slug) }
+    ///   }
+    /// }
+    ///
+    /// impl Borrow<Slug> for SlugBuf {
+    ///   fn borrow(&self) -> &Slug {
+    ///     Slug::new(&self.0)
+    ///   }
+    /// }
+    ///
+    /// impl ToOwned for Slug {
+    ///   type Owned = SlugBuf;
+    ///
+    ///   fn to_owned(&self) -> SlugBuf {
+    ///     SlugBuf(self.0.to_string())
+    ///   }
+    /// }
+    ///
+    /// impl Validate for &Slug {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+    ///       true => Ok(()),
+    ///       false => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// impl Validate for SlugBuf {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     <&Slug>::is_valid(&self.borrow(), ctx)
+    ///   }
+    /// }
+    ///
+    /// // `SlugBuf` accepts exactly what `&Slug` accepts
+    /// impl ValidToOwned for SlugBuf {}
+    ///
+    /// let slug: Valid<&Slug> = Slug::new("hello-world").validate().unwrap();
+    /// let owned: Valid<SlugBuf> = slug.to_owned_valid();
+    /// assert_eq!(owned.0, "hello-world");
+    /// ```
+    pub fn to_owned_valid(&self) -> Valid<T::Owned> {
+        Valid(self.0.to_owned())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Valid<T> {
+    /// Move this value into a pinned box
+    ///
+    /// This is only available with the `alloc` feature enabled.
+    ///
+    /// The value is validated before it is pinned, and since neither `Valid<T>` nor a pinned
+    /// reference allows mutable access to the inner value, it stays valid for as long as it is
+    /// pinned. [`Valid::as_pin_ref`] gives pinned access to the inner value:
+    /// ```
+    /// # use validity::*;
+    /// # use std::{marker::PhantomPinned, pin::Pin};
+    /// #[derive(Debug)]
+    /// struct Task {
+    ///   name: String,
+    ///   _pinned: PhantomPinned,
+    /// }
+    ///
+    /// impl Task {
+    ///   fn name(self: Pin<&Self>) -> &str {
+    ///     &self.get_ref().name
+    ///   }
+    /// }
+    ///
+    /// impl Validate for Task {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.name.is_empty() {
+    ///       true => Err(()),
+    ///       false => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let task = Task { name: "poll".to_string(), _pinned: PhantomPinned };
+    /// let task: Pin<Box<Valid<Task>>> = task.validate().unwrap().pin();
+    ///
+    /// assert_eq!(task.as_ref().as_pin_ref().name(), "poll");
+    /// assert_eq!(task.name, "poll");
+    /// ```
+    pub fn pin(self) -> Pin<alloc::boxed::Box<Self>> {
+        alloc::boxed::Box::pin(self)
+    }
+
+    /// Move this value onto the heap and leak it, returning a `'static` reference
+    ///
+    /// This is only available with the `alloc` feature enabled.
+    ///
+    /// The memory is intentionally never freed, so this should only be used for values which live
+    /// for the rest of the program, such as configuration validated once at startup:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Config {
+    ///   workers: usize,
+    /// }
+    ///
+    /// impl Validate for Config {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.workers {
+    ///       0 => Err(()),
+    ///       _ => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let config: &'static Valid<Config> = Config { workers: 4 }.validate().unwrap().leak();
+    ///
+    /// // usable from anywhere that needs a `'static` reference
+    /// let handle = std::thread::spawn(move || config.workers);
+    /// assert_eq!(handle.join().unwrap(), 4);
+    /// assert_eq!(config.workers, 4);
+    /// ```
+    pub fn leak(self) -> &'static Self
+    where
+        T: 'static,
+    {
+        alloc::boxed::Box::leak(alloc::boxed::Box::new(self))
+    }
+}
+
+impl<T> Valid<T> {
+    /// Project a pinned reference to a `Valid<T>` to a pinned reference to the inner value
+    ///
+    /// There is no mutable equivalent, since it would allow the inner value to be made invalid.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: `Valid<T>` never moves the inner value out of a reference, doesn't implement
+        // `Drop`, and is only `Unpin` when `T` is, so pinning is structural
+        unsafe { self.map_unchecked(|valid| &valid.0) }
+    }
+}
+
+/// A value which is not known to be valid, usually because it was taken out of a `Valid<T>` to be
+/// mutated
+///
+/// Unlike `Valid<T>`, this allows mutable access to the inner value. It can be turned back into a
+/// `Valid<T>` by validating it again.
+///
+/// An `Unvalidated<T>` is created with [`Valid::downgrade`] (or [`Unvalidated::new`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unvalidated<T>(T);
+
+impl<T> Unvalidated<T> {
+    /// Wrap a value which has not been validated
+    pub fn new(t: T) -> Self {
+        Self(t)
+    }
+
+    /// Consume self and return the inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Validate> Unvalidated<T> {
+    /// Validate the inner value with the given context
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn validate_with(self, ctx: T::Context<'_>) -> Result<Valid<T>, Failure<T>> {
+        self.0.validate_with(ctx)
+    }
+
+    /// Validate the inner value
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn validate(self) -> Result<Valid<T>, Failure<T>>
+    where
+        T: for<'a> Validate<Context<'a> = ()>,
+    {
+        self.0.validate()
+    }
+}
+
+impl<T> Deref for Unvalidated<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Unvalidated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 /// A trait which defines what it means for a type to be "valid"
 ///
 /// Because validity is defined by a trait, each type has a single definition of "valid", so
@@ -223,6 +1145,7 @@ pub trait Validate {
     fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error>;
 
     /// Validate with the given context
+    #[must_use = "validation may fail, so its result should be checked"]
     fn validate_with(self, ctx: Self::Context<'_>) -> Result<Valid<Self>, Failure<Self>>
     where
         Self: Sized,
@@ -236,7 +1159,31 @@ pub trait Validate {
     /// Validate this object, and if successful return a `Valid<Self>` which acts as a "proof of
     /// validity"
     ///
-    /// If validation fails,
+    /// If validation fails, the value is returned along with the error in a [`Failure`].
+    ///
+    /// The result is `#[must_use]` (as are the results of the other validation methods), since
+    /// ignoring it usually means the original value is about to be used without checking it:
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Even(u32);
+    ///
+    /// impl Validate for Even {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 % 2 {
+    ///       0 => Ok(()),
+    ///       _ => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// Even(3).validate();
+    /// ```
+    #[must_use = "validation may fail, so its result should be checked"]
     fn validate(self) -> Result<Valid<Self>, Failure<Self>>
     where
         Self: for<'a> Validate<Context<'a> = ()>,
@@ -244,8 +1191,452 @@ pub trait Validate {
     {
         self.validate_with(())
     }
+
+    /// Validate with the given context, discarding the error if validation fails
+    #[must_use = "validation may fail, so its result should be checked"]
+    fn validate_ok_with(self, ctx: Self::Context<'_>) -> Option<Valid<Self>>
+    where
+        Self: Sized,
+    {
+        self.validate_with(ctx).ok()
+    }
+
+    /// Validate this object, discarding the error if validation fails
+    ///
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Even(u32);
+    ///
+    /// impl Validate for Even {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 % 2 {
+    ///       0 => Ok(()),
+    ///       _ => Err(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// assert_eq!(Even(2).validate_ok().map(|e| e.0), Some(2));
+    /// assert!(Even(3).validate_ok().is_none());
+    ///
+    /// assert_eq!(Even(4).validate_ok_with(()).map(|e| e.0), Some(4));
+    /// assert!(Even(5).validate_ok_with(()).is_none());
+    /// ```
+    #[must_use = "validation may fail, so its result should be checked"]
+    fn validate_ok(self) -> Option<Valid<Self>>
+    where
+        Self: for<'a> Validate<Context<'a> = ()>,
+        Self: Sized,
+    {
+        self.validate_ok_with(())
+    }
+
+    /// Get [`Validate::is_valid`] as a plain function pointer
+    ///
+    /// This is useful for building dispatch tables of validators. The context lifetime is
+    /// higher-ranked (see [`ValidatorFn`]), so the function can be called with any context:
+    /// ```
+    /// # use validity::*;
+    /// # use std::collections::HashMap;
+    /// #[derive(Debug)]
+    /// struct Username(String);
+    ///
+    /// #[derive(Debug)]
+    /// struct Email(String);
+    ///
+    /// impl Validate for Username {
+    ///   type Context<'a> = ();
+    ///   type Error = &'static str;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.chars().all(char::is_alphanumeric) {
+    ///       true => Ok(()),
+    ///       false => Err("invalid username"),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// impl Validate for Email {
+    ///   type Context<'a> = ();
+    ///   type Error = &'static str;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0.contains('@') {
+    ///       true => Ok(()),
+    ///       false => Err("invalid email"),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let username: ValidatorFn<Username> = Username::validator();
+    /// assert!(username(&Username("alice".into()), ()).is_ok());
+    ///
+    /// // non-capturing closures coerce to function pointers too
+    /// let mut fields: HashMap<&str, fn(String) -> Result<(), &'static str>> = HashMap::new();
+    /// fields.insert("username", |s| Username::validator()(&Username(s), ()));
+    /// fields.insert("email", |s| Email::validator()(&Email(s), ()));
+    ///
+    /// assert_eq!(fields["username"]("bob".into()), Ok(()));
+    /// assert_eq!(fields["username"]("b o b".into()), Err("invalid username"));
+    /// assert_eq!(fields["email"]("bob@example.com".into()), Ok(()));
+    /// assert_eq!(fields["email"]("bob".into()), Err("invalid email"));
+    /// ```
+    fn validator() -> ValidatorFn<Self>
+    where
+        Self: Sized,
+    {
+        Self::is_valid
+    }
+
+    /// Validate with the given context, panicking with a readable message if validation fails
+    ///
+    /// This is only available with the `test-mock` feature enabled.
+    ///
+    /// Unlike `validate_with(ctx).unwrap()`, this doesn't need `Self` or `Self::Error` to be
+    /// `Debug`, and the panic message includes the name of the type and the `Display` output of
+    /// the error:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Port(u16);
+    ///
+    /// struct PortIsZero;
+    ///
+    /// impl std::fmt::Display for PortIsZero {
+    ///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///     write!(f, "port must not be zero")
+    ///   }
+    /// }
+    ///
+    /// impl Validate for Port {
+    ///   type Context<'a> = ();
+    ///   type Error = PortIsZero;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 {
+    ///       0 => Err(PortIsZero),
+    ///       _ => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let port = Port(80).validate_expect((), "valid port");
+    /// assert_eq!(port.0, 80);
+    ///
+    /// let panic = std::panic::catch_unwind(|| Port(0).validate_expect((), "valid port"));
+    /// let message = *panic.unwrap_err().downcast::<String>().unwrap();
+    ///
+    /// assert!(message.contains("valid port"));
+    /// assert!(message.contains("Port"));
+    /// assert!(message.contains("port must not be zero"));
+    /// ```
+    #[cfg(feature = "test-mock")]
+    #[track_caller]
+    fn validate_expect(self, ctx: Self::Context<'_>, msg: &str) -> Valid<Self>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Display,
+    {
+        match self.validate_with(ctx) {
+            Ok(valid) => valid,
+            Err(failure) => {
+                let type_name = core::any::type_name::<Self>();
+                panic!("{msg}: `{type_name}` failed validation: {}", failure.error)
+            }
+        }
+    }
+
+    /// Validate this object, using the global [`ValidationCache`] to skip validation if an
+    /// identical value has already been validated successfully
+    ///
+    /// This is only available with the `std` feature enabled.
+    ///
+    /// The global cache is shared by the whole process, so it is useful when unrelated parts of an
+    /// application validate the same (expensive to validate) values:
+    /// ```
+    /// # use validity::*;
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// static CALLS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(Debug, Hash)]
+    /// struct Document(String);
+    ///
+    /// impl Validate for Document {
+    ///   type Context<'a> = ();
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     CALLS.fetch_add(1, Ordering::SeqCst);
+    ///     Ok(())
+    ///   }
+    /// }
+    ///
+    /// Document("hello".to_string()).validate_cached_global().unwrap();
+    /// Document("hello".to_string()).validate_cached_global().unwrap();
+    /// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "validation may fail, so its result should be checked"]
+    fn validate_cached_global(self) -> Result<Valid<Self>, Failure<Self>>
+    where
+        Self: for<'a> Validate<Context<'a> = ()>,
+        Self: core::hash::Hash + Sized + 'static,
+    {
+        cache::validate_cached_global(self)
+    }
+
+    /// Validate with the given context, converting any error into an [`anyhow::Error`]
+    ///
+    /// This is only available with the `anyhow` feature enabled.
+    ///
+    /// The error is given context which includes the name of the type that failed validation:
+    /// ```
+    /// # use validity::*;
+    /// #[derive(Debug)]
+    /// struct Port(u16);
+    ///
+    /// #[derive(Debug)]
+    /// struct PortIsZero;
+    ///
+    /// impl std::fmt::Display for PortIsZero {
+    ///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///     write!(f, "port must not be zero")
+    ///   }
+    /// }
+    ///
+    /// impl std::error::Error for PortIsZero {}
+    ///
+    /// impl Validate for Port {
+    ///   type Context<'a> = ();
+    ///   type Error = PortIsZero;
+    ///
+    ///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match self.0 {
+    ///       0 => Err(PortIsZero),
+    ///       _ => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let error = Port(0).validate_anyhow(()).unwrap_err();
+    /// let message = format!("{error:#}");
+    ///
+    /// assert!(message.contains("Port"));
+    /// assert!(message.contains("port must not be zero"));
+    /// assert!(error.downcast_ref::<PortIsZero>().is_some());
+    /// ```
+    #[cfg(feature = "anyhow")]
+    #[must_use = "validation may fail, so its result should be checked"]
+    fn validate_anyhow(self, ctx: Self::Context<'_>) -> anyhow::Result<Valid<Self>>
+    where
+        Self: Sized,
+        Self::Error: std::error::Error + Send + Sync + 'static,
+    {
+        self.validate_with(ctx).map_err(|failure| {
+            let type_name = core::any::type_name::<Self>();
+            anyhow::Error::new(failure.error)
+                .context(alloc::format!("failed to validate `{type_name}`"))
+        })
+    }
+
+    /// Wait for the context to be ready, then validate with it
+    ///
+    /// This is only available with the `async` feature enabled.
+    ///
+    /// This is useful when the value is available before its context, for example if the context
+    /// has to be fetched from a database:
+    /// ```
+    /// # use validity::*;
+    /// # use std::time::Duration;
+    /// struct Blocklist(Vec<&'static str>);
+    ///
+    /// #[derive(Debug)]
+    /// struct Username(&'static str);
+    ///
+    /// impl Validate for Username {
+    ///   type Context<'a> = Blocklist;
+    ///   type Error = ();
+    ///
+    ///   fn is_valid(&self, blocklist: Self::Context<'_>) -> Result<(), Self::Error> {
+    ///     match blocklist.0.contains(&self.0) {
+    ///       true => Err(()),
+    ///       false => Ok(()),
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// async fn fetch_blocklist() -> Blocklist {
+    ///   tokio::time::sleep(Duration::from_millis(10)).await;
+    ///   Blocklist(vec!["admin"])
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let name = Username("alice").validate_when_ready(fetch_blocklist()).await.unwrap();
+    /// assert_eq!(name.into_inner().0, "alice");
+    ///
+    /// assert!(Username("admin").validate_when_ready(fetch_blocklist()).await.is_err());
+    /// # });
+    /// ```
+    ///
+    /// The returned future does nothing until it is polled. If it is dropped before the context
+    /// is ready, the context future is dropped too, and validation never happens.
+    #[cfg(feature = "async")]
+    fn validate_when_ready<'a, F>(
+        self,
+        ctx: F,
+    ) -> impl core::future::Future<Output = Result<Valid<Self>, Failure<Self>>>
+    where
+        Self: Sized,
+        F: core::future::Future<Output = Self::Context<'a>>,
+    {
+        async move { self.validate_with(ctx.await) }
+    }
 }
 
+/// The type of [`Validate::is_valid`] as a function pointer, returned by [`Validate::validator`]
+pub type ValidatorFn<T> =
+    for<'a, 'c> fn(&'a T, <T as Validate>::Context<'c>) -> Result<(), <T as Validate>::Error>;
+
+/// A shorthand for types which can be validated without any context
+///
+/// This is implemented for every type whose `Context` is `()`. Since `Context` is a generic
+/// associated type, bounding on it directly requires a higher-ranked bound
+/// (`for<'a> Validate<Context<'a> = ()>`), which is awkward to write, especially in return-position
+/// `impl Trait`. This trait can be used instead:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, PartialEq)]
+/// struct OutOfRange;
+///
+/// #[derive(Debug)]
+/// struct Percentage(u8);
+///
+/// impl Validate for Percentage {
+///   type Context<'a> = ();
+///   type Error = OutOfRange;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 <= 100 {
+///       true => Ok(()),
+///       false => Err(OutOfRange),
+///     }
+///   }
+/// }
+///
+/// fn percentage(value: u8) -> impl SimpleValidate<Error = OutOfRange> + std::fmt::Debug {
+///   Percentage(value)
+/// }
+///
+/// assert!(percentage(50).validate().is_ok());
+/// assert_eq!(percentage(150).validate().unwrap_err().error, OutOfRange);
+/// ```
+pub trait SimpleValidate: for<'a> Validate<Context<'a> = ()> {}
+
+impl<T> SimpleValidate for T where T: for<'a> Validate<Context<'a> = ()> {}
+
+/// A marker trait for types where every possible value is valid
+///
+/// Types implementing this trait can be wrapped with [`Valid::trusted`], which skips
+/// [`Validate::is_valid`] entirely. This is mostly useful for enums where each variant is valid by
+/// construction, but which are validated so they can be used alongside other validated types.
+///
+/// Implementing this trait for a type which has invalid values is a logic error, since it allows
+/// creating a `Valid<T>` which isn't valid:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Direction {
+///   North,
+///   East,
+///   South,
+///   West,
+/// }
+///
+/// impl Validate for Direction {
+///   type Context<'a> = ();
+///   type Error = core::convert::Infallible;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     Ok(())
+///   }
+/// }
+///
+/// impl AlwaysValid for Direction {}
+///
+/// let direction = Valid::trusted(Direction::North);
+/// assert_eq!(*direction, Direction::North);
+/// ```
+/// Types which don't implement `AlwaysValid` can't be trusted:
+/// ```compile_fail
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Even(u32);
+///
+/// impl Validate for Even {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 % 2 == 0 {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// let even = Valid::trusted(Even(3));
+/// ```
+pub trait AlwaysValid: Validate {}
+
+/// A marker for owned types which are valid whenever their borrowed form is valid
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// Implementing this trait is a promise that, for any `&T` which is valid (where `T::Owned` is
+/// `Self`), `T::to_owned` produces a valid `Self`. This allows [`Valid::to_owned_valid`] to skip
+/// validating the owned value. If `Self` has stricter rules than its borrowed form, don't
+/// implement this trait. Without it, the owned value must be validated:
+/// ```compile_fail
+/// # use validity::*;
+/// #[derive(Debug, Clone)]
+/// struct Config {
+///   retries: u8,
+/// }
+///
+/// // lenient when borrowed
+/// impl Validate for &Config {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     Ok(())
+///   }
+/// }
+///
+/// // but strict when owned
+/// impl Validate for Config {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.retries {
+///       0 => Err(()),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let config = Config { retries: 0 };
+/// let borrowed: Valid<&Config> = (&config).validate().unwrap();
+/// let owned: Valid<Config> = borrowed.to_owned_valid();
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ValidToOwned {}
+
 /// A struct representing a failure to validate a value
 ///
 /// It contains both the value that failed validation, as well as the error that caused that error
@@ -0,0 +1,165 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{Failure, Valid, Validate};
+
+/// Limits on how much work a recursive validator may do
+///
+/// These protect against untrusted input which is nested deeply enough to overflow the stack, or
+/// is large enough to take an unreasonable amount of time to validate. See [`validate_with_limits`]
+/// for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationLimits {
+    /// The maximum nesting depth of the value
+    pub max_depth: usize,
+    /// The maximum total number of nodes which will be validated
+    pub max_nodes: usize,
+}
+
+/// The error returned when a value exceeds its [`ValidationLimits`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitExceeded {
+    /// The value was nested more deeply than `max_depth`
+    Depth,
+    /// The value contained more than `max_nodes` nodes
+    Nodes,
+}
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Depth => write!(f, "maximum validation depth exceeded"),
+            Self::Nodes => write!(f, "maximum number of validated nodes exceeded"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
+
+/// Tracks the depth and number of nodes visited while validating, and enforces
+/// [`ValidationLimits`]
+///
+/// Recursive validators take a `&mut LimitTracker` as their context, and validate each node
+/// inside [`LimitTracker::visit`].
+#[derive(Debug, Clone)]
+pub struct LimitTracker {
+    limits: ValidationLimits,
+    depth: usize,
+    nodes: usize,
+}
+
+impl LimitTracker {
+    /// Create a new tracker which enforces the given limits
+    pub fn new(limits: ValidationLimits) -> Self {
+        Self {
+            limits,
+            depth: 0,
+            nodes: 0,
+        }
+    }
+
+    /// The limits enforced by this tracker
+    pub fn limits(&self) -> ValidationLimits {
+        self.limits
+    }
+
+    /// The current nesting depth
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of nodes visited so far
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Visit a single node, calling `f` to validate it (and its children) one level deeper
+    ///
+    /// If visiting this node would exceed either limit, `f` is not called, and a
+    /// [`LimitExceeded`] error is returned instead
+    pub fn visit<E>(&mut self, f: impl FnOnce(&mut Self) -> Result<(), E>) -> Result<(), E>
+    where
+        E: From<LimitExceeded>,
+    {
+        if self.depth >= self.limits.max_depth {
+            return Err(LimitExceeded::Depth.into());
+        }
+
+        if self.nodes >= self.limits.max_nodes {
+            return Err(LimitExceeded::Nodes.into());
+        }
+
+        self.depth += 1;
+        self.nodes += 1;
+        let result = f(self);
+        self.depth -= 1;
+
+        result
+    }
+}
+
+/// Validate a recursive value, failing with [`LimitExceeded`] if it is too deep or too large
+///
+/// The value's context is a `&mut LimitTracker`, and its `Validate` impl should wrap the
+/// validation of each node in [`LimitTracker::visit`], passing the tracker on to its children:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// enum Tree {
+///   Leaf(i32),
+///   Node(Vec<Tree>),
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidTree {
+///   NegativeLeaf,
+///   Limit(LimitExceeded),
+/// }
+///
+/// impl From<LimitExceeded> for InvalidTree {
+///   fn from(e: LimitExceeded) -> Self {
+///     Self::Limit(e)
+///   }
+/// }
+///
+/// impl Validate for Tree {
+///   type Context<'a> = &'a mut LimitTracker;
+///   type Error = InvalidTree;
+///
+///   fn is_valid(&self, tracker: Self::Context<'_>) -> Result<(), Self::Error> {
+///     tracker.visit(|tracker| match self {
+///       Tree::Leaf(i) if *i < 0 => Err(InvalidTree::NegativeLeaf),
+///       Tree::Leaf(_) => Ok(()),
+///       Tree::Node(children) => children.iter().try_for_each(|child| child.is_valid(tracker)),
+///     })
+///   }
+/// }
+///
+/// let limits = ValidationLimits { max_depth: 64, max_nodes: 1000 };
+///
+/// let tree = Tree::Node(vec![Tree::Leaf(1), Tree::Node(vec![Tree::Leaf(2)])]);
+/// assert!(validate_with_limits(tree, limits).is_ok());
+///
+/// let tree = Tree::Node(vec![Tree::Leaf(-1)]);
+/// let failure = validate_with_limits(tree, limits).unwrap_err();
+/// assert_eq!(failure.error, InvalidTree::NegativeLeaf);
+///
+/// // a deeply nested tree is rejected once it reaches `max_depth`, rather than recursing forever
+/// let mut tree = Tree::Leaf(1);
+/// for _ in 0..10_000 {
+///   tree = Tree::Node(vec![tree]);
+/// }
+/// let failure = validate_with_limits(tree, limits).unwrap_err();
+/// assert_eq!(failure.error, InvalidTree::Limit(LimitExceeded::Depth));
+///
+/// let tree = Tree::Node((0..2000).map(Tree::Leaf).collect());
+/// let failure = validate_with_limits(tree, limits).unwrap_err();
+/// assert_eq!(failure.error, InvalidTree::Limit(LimitExceeded::Nodes));
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_with_limits<T>(value: T, limits: ValidationLimits) -> Result<Valid<T>, Failure<T>>
+where
+    T: for<'a> Validate<Context<'a> = &'a mut LimitTracker>,
+{
+    value.validate_with(&mut LimitTracker::new(limits))
+}
@@ -0,0 +1,58 @@
+use crate::{Failure, Valid, Validate};
+
+/// A type which has a canonical form, which should be produced before validating
+///
+/// [`Validate::is_valid`] only borrows the value, so it can't fix up insignificant differences
+/// (such as surrounding whitespace or letter case). Implementing this trait allows
+/// [`Normalize::validate_normalized`] to normalize the value first, so that the `Valid<T>` always
+/// contains canonical data:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct TooLong;
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = TooLong;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.len() <= 5 {
+///       true => Ok(()),
+///       false => Err(TooLong),
+///     }
+///   }
+/// }
+///
+/// impl Normalize for Username {
+///   fn normalize(self) -> Self {
+///     Username(self.0.trim().to_string())
+///   }
+/// }
+///
+/// // the padding would make this too long, but it is trimmed before validating
+/// assert!(Username("   alice".to_string()).validate().is_err());
+///
+/// let name = Username("   alice".to_string()).validate_normalized(()).unwrap();
+/// assert_eq!(name.0, "alice");
+///
+/// let failure = Username("  alice bob ".to_string()).validate_normalized(()).unwrap_err();
+/// assert_eq!(failure.error, TooLong);
+/// assert_eq!(failure.value.0, "alice bob");
+/// ```
+pub trait Normalize: Validate + Sized {
+    /// Convert this value into its canonical form
+    ///
+    /// Normalizing a value which is already normalized should return it unchanged
+    fn normalize(self) -> Self;
+
+    /// Normalize this value, then validate the normalized value with the given context
+    ///
+    /// If validation fails, the [`Failure`] contains the normalized value
+    #[must_use = "validation may fail, so its result should be checked"]
+    fn validate_normalized(self, ctx: Self::Context<'_>) -> Result<Valid<Self>, Failure<Self>> {
+        self.normalize().validate_with(ctx)
+    }
+}
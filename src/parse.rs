@@ -0,0 +1,71 @@
+use nom::{
+    error::{ErrorKind, FromExternalError, ParseError},
+    IResult, Parser,
+};
+
+use crate::{Valid, Validate};
+
+/// Run a `nom` parser, then validate its output
+///
+/// This is only available with the `nom` feature enabled.
+///
+/// If the value fails validation, the parser fails with a recoverable [`nom::Err::Error`] of kind
+/// [`ErrorKind::Verify`], positioned at the start of the value. The validation error is passed to
+/// the error type's [`FromExternalError`] impl, so a custom error type can keep it:
+/// ```
+/// # use validity::*;
+/// # use nom::{character::complete::{char, u8}, error::{Error, ErrorKind}, sequence::separated_pair, Parser};
+/// #[derive(Debug)]
+/// struct Time {
+///   hour: u8,
+///   minute: u8,
+/// }
+///
+/// impl Validate for Time {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.hour >= 24 {
+///       return Err("hour out of range");
+///     }
+///     if self.minute >= 60 {
+///       return Err("minute out of range");
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// fn time(input: &str) -> nom::IResult<&str, Valid<Time>> {
+///   validated(separated_pair(u8, char(':'), u8).map(|(hour, minute)| Time { hour, minute }))
+///     .parse(input)
+/// }
+///
+/// let (rest, parsed) = time("09:30 UTC").unwrap();
+/// assert_eq!((parsed.hour, parsed.minute), (9, 30));
+/// assert_eq!(rest, " UTC");
+///
+/// let nom::Err::Error(error) = time("25:00 UTC").unwrap_err() else {
+///   panic!("expected a recoverable error");
+/// };
+/// assert_eq!(error, Error::new("25:00 UTC", ErrorKind::Verify));
+/// ```
+pub fn validated<I, T, E, P>(mut parser: P) -> impl FnMut(I) -> IResult<I, Valid<T>, E>
+where
+    I: Clone,
+    T: for<'a> Validate<Context<'a> = ()>,
+    E: ParseError<I> + FromExternalError<I, T::Error>,
+    P: Parser<I, Output = T, Error = E>,
+{
+    move |input: I| {
+        let (rest, value) = parser.parse(input.clone())?;
+        match value.validate() {
+            Ok(value) => Ok((rest, value)),
+            Err(failure) => Err(nom::Err::Error(E::from_external_error(
+                input,
+                ErrorKind::Verify,
+                failure.error,
+            ))),
+        }
+    }
+}
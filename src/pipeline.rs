@@ -0,0 +1,133 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{Valid, Validate};
+
+/// A sequence of validation stages, which are run one after another
+///
+/// Each stage takes the output of the previous stage, and either transforms it into the input of
+/// the next stage, or fails. Once every stage has succeeded, the final output is validated with
+/// its [`Validate`] impl to produce a `Valid<T>`.
+///
+/// If any stage fails, the remaining stages are skipped, and the error is returned along with the
+/// index of the stage that failed. Failure of the final validation is reported with an index equal
+/// to the number of stages:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidPort {
+///   Empty,
+///   NotANumber,
+///   Zero,
+/// }
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = InvalidPort;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(InvalidPort::Zero),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let pipeline = Pipeline::new()
+///   .then(|s: &str| match s.trim() {
+///     "" => Err(InvalidPort::Empty),
+///     s => Ok(s),
+///   })
+///   .then(|s: &str| s.parse::<u16>().map_err(|_| InvalidPort::NotANumber))
+///   .then(|i: u16| Ok(Port(i)));
+///
+/// assert_eq!(pipeline.run(" 8080 ", ()).unwrap().0, 8080);
+///
+/// let error = pipeline.run(" eighty ", ()).unwrap_err();
+/// assert_eq!(error.stage, 1);
+/// assert_eq!(error.error, InvalidPort::NotANumber);
+///
+/// let error = pipeline.run("0", ()).unwrap_err();
+/// assert_eq!(error.stage, 3);
+/// assert_eq!(error.error, InvalidPort::Zero);
+/// ```
+#[derive(Clone, Copy)]
+#[must_use = "pipelines do nothing unless `run` is called"]
+pub struct Pipeline<F> {
+    run: F,
+    stages: usize,
+}
+
+/// The error returned when a [`Pipeline`] fails
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StageError<E> {
+    /// The index of the stage that failed
+    pub stage: usize,
+    /// The error that was generated
+    pub error: E,
+}
+
+impl<T, E> Pipeline<fn(T) -> Result<T, StageError<E>>> {
+    /// Create a new pipeline with no stages
+    pub fn new() -> Self {
+        Self { run: Ok, stages: 0 }
+    }
+}
+
+impl<T, E> Default for Pipeline<fn(T) -> Result<T, StageError<E>>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> Pipeline<F> {
+    /// The number of stages in this pipeline
+    pub fn stages(&self) -> usize {
+        self.stages
+    }
+
+    /// Add a stage to the end of this pipeline
+    pub fn then<T, U, V, E, S>(self, stage: S) -> Pipeline<impl Fn(T) -> Result<V, StageError<E>>>
+    where
+        F: Fn(T) -> Result<U, StageError<E>>,
+        S: Fn(U) -> Result<V, E>,
+    {
+        let Self { run, stages } = self;
+
+        Pipeline {
+            run: move |t| {
+                let u = run(t)?;
+                stage(u).map_err(|error| StageError {
+                    stage: stages,
+                    error,
+                })
+            },
+            stages: stages + 1,
+        }
+    }
+
+    /// Run each stage in order, then validate the output of the last stage with the given context
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn run<T, O, E>(&self, input: T, ctx: O::Context<'_>) -> Result<Valid<O>, StageError<E>>
+    where
+        F: Fn(T) -> Result<O, StageError<E>>,
+        O: Validate,
+        E: From<O::Error>,
+    {
+        let output = (self.run)(input)?;
+        output.validate_with(ctx).map_err(|failure| StageError {
+            stage: self.stages,
+            error: failure.error.into(),
+        })
+    }
+}
+
+impl<F> Debug for Pipeline<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("stages", &self.stages)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,208 @@
+use core::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/// A single, independent check which can be proven about a `T`
+///
+/// Implement this on a marker type for each invariant. See [`ProvenValid`] for an example.
+pub trait Proof<T: ?Sized> {
+    /// The error returned if the check fails
+    type Error;
+
+    /// Perform the check
+    fn check(value: &T) -> Result<(), Self::Error>;
+}
+
+/// A value, along with type-level proof of which [`Proof`]s have been checked
+///
+/// `Valid<T>` has a single definition of "valid" for each type. For types with several independent
+/// invariants, `ProvenValid<T, P>` tracks which of them have been checked, where `P` is a list of
+/// proof markers built up from `()` by [`ProvenValid::prove`] (e.g. `(DnsChecked,
+/// (SyntaxChecked, ()))`).
+///
+/// A function can demand a particular proof by taking a `ProvenValid<T, Marker>`, and callers can
+/// only produce one with [`ProvenValid::select`] if `Marker` is in their list of proofs:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Email(String);
+///
+/// struct SyntaxChecked;
+/// struct DnsChecked;
+///
+/// impl Proof<Email> for SyntaxChecked {
+///   type Error = &'static str;
+///
+///   fn check(email: &Email) -> Result<(), Self::Error> {
+///     match email.0.split_once('@') {
+///       Some((user, domain)) if !user.is_empty() && !domain.is_empty() => Ok(()),
+///       _ => Err("invalid syntax"),
+///     }
+///   }
+/// }
+///
+/// impl Proof<Email> for DnsChecked {
+///   type Error = &'static str;
+///
+///   fn check(email: &Email) -> Result<(), Self::Error> {
+///     match email.0.ends_with("@example.com") {
+///       true => Ok(()),
+///       false => Err("domain does not resolve"),
+///     }
+///   }
+/// }
+///
+/// fn send_email(email: ProvenValid<Email, DnsChecked>) -> String {
+///   format!("sent to {}", email.0)
+/// }
+///
+/// let email = ProvenValid::new(Email("alice@example.com".to_string()))
+///   .prove::<SyntaxChecked>()
+///   .unwrap()
+///   .prove::<DnsChecked>()
+///   .unwrap();
+///
+/// assert_eq!(send_email(email.select()), "sent to alice@example.com");
+///
+/// let failure = ProvenValid::new(Email("alice@nowhere".to_string()))
+///   .prove::<DnsChecked>()
+///   .unwrap_err();
+/// assert_eq!(failure.error, "domain does not resolve");
+/// ```
+///
+/// A value which has only had its syntax checked can't be passed to `send_email`:
+/// ```compile_fail
+/// # use validity::*;
+/// # #[derive(Debug)]
+/// # struct Email(String);
+/// # struct SyntaxChecked;
+/// # struct DnsChecked;
+/// # impl Proof<Email> for SyntaxChecked {
+/// #   type Error = ();
+/// #   fn check(_: &Email) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// fn send_email(email: ProvenValid<Email, DnsChecked>) {}
+///
+/// let email = ProvenValid::new(Email("alice@example.com".to_string()))
+///   .prove::<SyntaxChecked>()
+///   .unwrap();
+///
+/// send_email(email.select());
+/// ```
+pub struct ProvenValid<T, P = ()> {
+    value: T,
+    proofs: PhantomData<fn() -> P>,
+}
+
+impl<T> ProvenValid<T> {
+    /// Wrap a value, with no proofs
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            proofs: PhantomData,
+        }
+    }
+}
+
+impl<T, P> ProvenValid<T, P> {
+    /// Check `Q`, and if it passes, add it to the list of proofs
+    ///
+    /// If the check fails, the error is returned along with the value (with its existing proofs)
+    pub fn prove<Q>(self) -> ProveResult<T, P, Q>
+    where
+        Q: Proof<T>,
+    {
+        match Q::check(&self.value) {
+            Ok(()) => Ok(ProvenValid {
+                value: self.value,
+                proofs: PhantomData,
+            }),
+            Err(error) => Err(ProofFailure { value: self, error }),
+        }
+    }
+
+    /// Keep only the proof `Q`, which must already be in the list of proofs
+    ///
+    /// The index `I` is inferred, and only exists to make the lookup unambiguous
+    pub fn select<Q, I>(self) -> ProvenValid<T, Q>
+    where
+        P: Contains<Q, I>,
+    {
+        ProvenValid {
+            value: self.value,
+            proofs: PhantomData,
+        }
+    }
+
+    /// Consume self and return the inner value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, P> Deref for ProvenValid<T, P> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: Debug, P> Debug for ProvenValid<T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ProvenValid").field(&self.value).finish()
+    }
+}
+
+impl<T: Clone, P> Clone for ProvenValid<T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            proofs: PhantomData,
+        }
+    }
+}
+
+/// The result of [`ProvenValid::prove`], which adds the proof `Q` to the proofs `P`
+pub type ProveResult<T, P, Q> = Result<ProvenValid<T, (Q, P)>, ProofFailure<T, P, Q>>;
+
+/// The error returned by [`ProvenValid::prove`] when the check `Q` fails
+pub struct ProofFailure<T, P, Q: Proof<T>> {
+    /// The value that failed the check, along with the proofs it already had
+    pub value: ProvenValid<T, P>,
+    /// The error that was generated
+    pub error: Q::Error,
+}
+
+impl<T, P, Q> Debug for ProofFailure<T, P, Q>
+where
+    T: Debug,
+    Q: Proof<T>,
+    Q::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProofFailure")
+            .field("value", &self.value)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// The index of a proof at the head of a list of proofs, used by [`Contains`]
+#[derive(Debug)]
+pub enum Here {}
+
+/// The index of a proof in the tail of a list of proofs, used by [`Contains`]
+#[derive(Debug)]
+pub struct There<I>(PhantomData<I>);
+
+/// A list of proofs which contains the proof `Q`, at index `I`
+///
+/// This is implemented for the nested tuples built by [`ProvenValid::prove`], and shouldn't need
+/// to be implemented manually.
+pub trait Contains<Q, I> {}
+
+impl<Q, Rest> Contains<Q, Here> for (Q, Rest) {}
+
+impl<Q, Head, Rest, I> Contains<Q, There<I>> for (Head, Rest) where Rest: Contains<Q, I> {}
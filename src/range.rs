@@ -0,0 +1,168 @@
+use core::{
+    fmt::{Display, Formatter},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{And, Or};
+
+/// A reusable check of a value, independent of the value's own [`Validate`](crate::Validate) impl
+///
+/// This allows generic rules (such as [`InRange`]) to be written once and used from the
+/// `Validate` impls of many types. Validators can be combined with [`Validator::and`] and
+/// [`Validator::or`], or with the `&` and `|` operators.
+pub trait Validator<T: ?Sized> {
+    /// The error returned when the check fails
+    type Error;
+
+    /// Check `value`, returning an error if it fails
+    fn check(&self, value: &T) -> Result<(), Self::Error>;
+
+    /// Combine this with another validator, both of which must pass (the same as `self & other`)
+    fn and<V: Validator<T>>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+    {
+        And::new(self, other)
+    }
+
+    /// Combine this with another validator, either of which must pass (the same as `self | other`)
+    fn or<V: Validator<T>>(self, other: V) -> Or<Self, V>
+    where
+        Self: Sized,
+    {
+        Or::new(self, other)
+    }
+}
+
+/// A [`Validator`] which checks that a value is within a range
+///
+/// Any [`RangeBounds`] can be used, so each end can be inclusive, exclusive or unbounded. The
+/// error contains the bound which was violated:
+/// ```
+/// # use validity::*;
+/// # use std::ops::Bound;
+/// let percent = InRange::new(0..=100);
+/// assert_eq!(percent.check(&0), Ok(()));
+/// assert_eq!(percent.check(&100), Ok(()));
+/// assert_eq!(percent.check(&-1), Err(RangeError::TooLow(Bound::Included(0))));
+/// assert_eq!(percent.check(&101), Err(RangeError::TooHigh(Bound::Included(100))));
+///
+/// let positive = InRange::new(1i64..);
+/// assert_eq!(positive.check(&1), Ok(()));
+/// assert_eq!(positive.check(&i64::MAX), Ok(()));
+/// assert_eq!(positive.check(&0), Err(RangeError::TooLow(Bound::Included(1))));
+///
+/// let small = InRange::new(..10.0);
+/// assert_eq!(small.check(&9.5), Ok(()));
+/// assert_eq!(small.check(&f64::MIN), Ok(()));
+/// assert_eq!(small.check(&10.0), Err(RangeError::TooHigh(Bound::Excluded(10.0))));
+/// ```
+/// Values which can't be compared with themselves (such as `NaN`) are never in range, even if the
+/// range is unbounded:
+/// ```
+/// # use validity::*;
+/// # use std::ops::Bound;
+/// let small = InRange::new(..10.0);
+/// assert_eq!(small.check(&f64::NAN), Err(RangeError::TooLow(Bound::Unbounded)));
+///
+/// let any = InRange::<f64>::new(..);
+/// assert_eq!(any.check(&f64::INFINITY), Ok(()));
+/// assert_eq!(any.check(&f64::NAN), Err(RangeError::TooLow(Bound::Unbounded)));
+/// ```
+/// It is typically used from a `Validate` impl:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Temperature(f64);
+///
+/// impl Validate for Temperature {
+///   type Context<'a> = ();
+///   type Error = RangeError<f64>;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     InRange::new(-273.15..).check(&self.0)
+///   }
+/// }
+///
+/// assert!(Temperature(20.0).validate().is_ok());
+/// let failure = Temperature(-300.0).validate().unwrap_err();
+/// assert_eq!(failure.error.to_string(), "value must be at least -273.15");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T> InRange<T> {
+    /// Create a validator for the given range
+    pub fn new(range: impl RangeBounds<T>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Validator<T> for InRange<T> {
+    type Error = RangeError<T>;
+
+    fn check(&self, value: &T) -> Result<(), Self::Error> {
+        // `NaN` compares false with everything, so it would pass an unbounded end (or both ends of
+        // `..`) if it weren't rejected up front
+        if value.partial_cmp(value).is_none() {
+            return Err(RangeError::TooLow(self.start.clone()));
+        }
+
+        let above_start = match &self.start {
+            Bound::Included(start) => value >= start,
+            Bound::Excluded(start) => value > start,
+            Bound::Unbounded => true,
+        };
+        if !above_start {
+            return Err(RangeError::TooLow(self.start.clone()));
+        }
+
+        let below_end = match &self.end {
+            Bound::Included(end) => value <= end,
+            Bound::Excluded(end) => value < end,
+            Bound::Unbounded => true,
+        };
+        if !below_end {
+            return Err(RangeError::TooHigh(self.end.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned by [`InRange`]
+///
+/// Values which can't be compared with themselves (such as `NaN`) are reported as too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeError<T> {
+    /// The value was below the start of the range
+    TooLow(Bound<T>),
+    /// The value was above the end of the range
+    TooHigh(Bound<T>),
+}
+
+impl<T: Display> Display for RangeError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooLow(Bound::Included(start)) => write!(f, "value must be at least {start}"),
+            Self::TooLow(Bound::Excluded(start)) => write!(f, "value must be greater than {start}"),
+            Self::TooHigh(Bound::Included(end)) => write!(f, "value must be at most {end}"),
+            Self::TooHigh(Bound::Excluded(end)) => write!(f, "value must be less than {end}"),
+            Self::TooLow(Bound::Unbounded) | Self::TooHigh(Bound::Unbounded) => {
+                write!(f, "value is out of range")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display + core::fmt::Debug> std::error::Error for RangeError<T> {}
@@ -0,0 +1,317 @@
+use alloc::{boxed::Box, string::String};
+use core::{
+    any::{type_name, Any},
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+};
+use std::{collections::HashMap, error::Error};
+
+use crate::Validate;
+
+/// An object-safe validator, for values whose type is only known at runtime
+///
+/// Both the value and the context are passed as `&dyn Any`, so it is up to the implementation to
+/// downcast them to the types it expects, and to return an error if they are the wrong type. This
+/// means that mistakes which [`Validate`] would catch at compile time (passing the wrong value or
+/// the wrong context) are only caught at runtime.
+///
+/// [`TypedValidator`] implements this trait for any type which implements [`Validate`].
+pub trait DynValidator {
+    /// Validate `value` with the given context
+    fn validate_dyn(&self, value: &dyn Any, ctx: &dyn Any) -> Result<(), Box<dyn Error>>;
+}
+
+/// A [`DynValidator`] which downcasts to `T`, and validates it with its [`Validate`] impl
+///
+/// The context is downcast to `T::Context<'static>`, and cloned before it is passed to
+/// [`Validate::is_valid`], so only types with a `'static` context are supported.
+pub struct TypedValidator<T>(PhantomData<fn(&T)>);
+
+impl<T> TypedValidator<T> {
+    /// Create a new `TypedValidator`
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for TypedValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for TypedValidator<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TypedValidator")
+            .field(&type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> DynValidator for TypedValidator<T>
+where
+    T: Validate + 'static,
+    T::Context<'static>: Clone + 'static,
+    T::Error: Error + 'static,
+{
+    fn validate_dyn(&self, value: &dyn Any, ctx: &dyn Any) -> Result<(), Box<dyn Error>> {
+        let value = value
+            .downcast_ref::<T>()
+            .ok_or(DynValidationError::WrongValueType {
+                expected: type_name::<T>(),
+            })?;
+        let ctx = ctx.downcast_ref::<T::Context<'static>>().ok_or(
+            DynValidationError::WrongContextType {
+                expected: type_name::<T::Context<'static>>(),
+            },
+        )?;
+
+        value.is_valid(ctx.clone())?;
+        Ok(())
+    }
+}
+
+/// A set of [`DynValidator`]s, looked up by a string key
+///
+/// This is intended for plugin architectures, where each plugin registers validators for its own
+/// types at startup, and the host validates values without knowing their concrete types:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// #[derive(Debug)]
+/// struct PortIsZero;
+///
+/// impl std::fmt::Display for PortIsZero {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     write!(f, "port must not be zero")
+///   }
+/// }
+///
+/// impl std::error::Error for PortIsZero {}
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = PortIsZero;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(PortIsZero),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// #[derive(Debug)]
+/// struct TooLong;
+///
+/// impl std::fmt::Display for TooLong {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     write!(f, "username too long")
+///   }
+/// }
+///
+/// impl std::error::Error for TooLong {}
+///
+/// impl Validate for Username {
+///   type Context<'a> = usize;  // the maximum length
+///   type Error = TooLong;
+///
+///   fn is_valid(&self, max_len: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.len() <= max_len {
+///       true => Ok(()),
+///       false => Err(TooLong),
+///     }
+///   }
+/// }
+///
+/// let mut registry = ValidatorRegistry::new();
+/// registry.register("port", TypedValidator::<Port>::new());
+/// registry.register("username", TypedValidator::<Username>::new());
+///
+/// assert!(registry.validate("port", &Port(80), &()).is_ok());
+/// assert!(registry.validate("username", &Username("alice".to_string()), &8usize).is_ok());
+///
+/// let error = registry.validate("port", &Port(0), &()).unwrap_err();
+/// assert_eq!(error.to_string(), "port must not be zero");
+///
+/// let error = registry.validate("username", &Username("alice".to_string()), &3usize).unwrap_err();
+/// assert_eq!(error.to_string(), "username too long");
+///
+/// // type mismatches and unknown keys are only caught at runtime
+/// assert!(registry.validate("port", &Username("alice".to_string()), &()).is_err());
+/// assert!(registry.validate("username", &Username("alice".to_string()), &()).is_err());
+/// assert!(registry.validate("email", &Port(80), &()).is_err());
+/// ```
+#[derive(Default)]
+pub struct ValidatorRegistry {
+    validators: HashMap<String, Box<dyn DynValidator + Send + Sync>>,
+}
+
+impl ValidatorRegistry {
+    /// Create a new, empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a validator with the given key, returning the validator previously registered
+    /// with that key (if any)
+    pub fn register(
+        &mut self,
+        key: impl Into<String>,
+        validator: impl DynValidator + Send + Sync + 'static,
+    ) -> Option<Box<dyn DynValidator + Send + Sync>> {
+        self.validators.insert(key.into(), Box::new(validator))
+    }
+
+    /// Get the validator registered with the given key
+    pub fn get(&self, key: &str) -> Option<&(dyn DynValidator + Send + Sync)> {
+        self.validators.get(key).map(|validator| &**validator)
+    }
+
+    /// Validate `value` with the validator registered with the given key
+    ///
+    /// If no validator is registered with that key, a [`DynValidationError::UnknownKey`] is
+    /// returned
+    pub fn validate(
+        &self,
+        key: &str,
+        value: &dyn Any,
+        ctx: &dyn Any,
+    ) -> Result<(), Box<dyn Error>> {
+        let validator = self
+            .get(key)
+            .ok_or_else(|| DynValidationError::UnknownKey(key.into()))?;
+        validator.validate_dyn(value, ctx)
+    }
+}
+
+impl Debug for ValidatorRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ValidatorRegistry")
+            .field("keys", &self.validators.keys())
+            .finish()
+    }
+}
+
+/// The errors returned by [`ValidatorRegistry`] and [`TypedValidator`] which aren't validation
+/// errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynValidationError {
+    /// No validator was registered with this key
+    UnknownKey(String),
+    /// The value was not of the type the validator expected
+    WrongValueType {
+        /// The name of the expected type
+        expected: &'static str,
+    },
+    /// The context was not of the type the validator expected
+    WrongContextType {
+        /// The name of the expected type
+        expected: &'static str,
+    },
+}
+
+impl Display for DynValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "no validator registered for `{key}`"),
+            Self::WrongValueType { expected } => write!(f, "expected a value of type `{expected}`"),
+            Self::WrongContextType { expected } => {
+                write!(f, "expected a context of type `{expected}`")
+            }
+        }
+    }
+}
+
+impl Error for DynValidationError {}
+
+/// Recovering concrete validation errors from boxed errors
+///
+/// Pipelines which mix validators (such as [`ValidatorRegistry`]) usually return
+/// `Box<dyn Error>`. [`downcast_validation`](ValidationErrorExt::downcast_validation) recovers
+/// the concrete error for specialized handling. Unlike [`Error::downcast_ref`], it also checks
+/// each error in the [`source`](Error::source) chain, so it still finds the validation error
+/// after it has been wrapped:
+/// ```
+/// # use validity::*;
+/// # use std::{error::Error, fmt};
+/// #[derive(Debug, PartialEq)]
+/// struct PortIsZero;
+///
+/// impl fmt::Display for PortIsZero {
+///   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     write!(f, "port must not be zero")
+///   }
+/// }
+///
+/// impl Error for PortIsZero {}
+///
+/// #[derive(Debug, PartialEq)]
+/// struct TooLong {
+///   max: usize,
+/// }
+///
+/// impl fmt::Display for TooLong {
+///   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     write!(f, "must be at most {} characters", self.max)
+///   }
+/// }
+///
+/// impl Error for TooLong {}
+///
+/// // wraps another error, with context for a human
+/// #[derive(Debug)]
+/// struct InField(&'static str, Box<dyn Error>);
+///
+/// impl fmt::Display for InField {
+///   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     write!(f, "invalid `{}`", self.0)
+///   }
+/// }
+///
+/// impl Error for InField {
+///   fn source(&self) -> Option<&(dyn Error + 'static)> {
+///     Some(&*self.1)
+///   }
+/// }
+///
+/// let errors: Vec<Box<dyn Error>> = vec![
+///   Box::new(PortIsZero),
+///   Box::new(InField("username", Box::new(TooLong { max: 16 }))),
+/// ];
+///
+/// assert_eq!(errors[0].downcast_validation::<PortIsZero>(), Some(&PortIsZero));
+/// assert_eq!(errors[0].downcast_validation::<TooLong>(), None);
+///
+/// assert_eq!(errors[1].downcast_validation::<TooLong>(), Some(&TooLong { max: 16 }));
+/// assert_eq!(errors[1].downcast_validation::<PortIsZero>(), None);
+/// ```
+pub trait ValidationErrorExt {
+    /// Find the first error of type `E`, starting with this error and then following its sources
+    fn downcast_validation<E: Error + 'static>(&self) -> Option<&E>;
+}
+
+impl ValidationErrorExt for dyn Error + 'static {
+    fn downcast_validation<E: Error + 'static>(&self) -> Option<&E> {
+        let mut current = Some(self);
+        while let Some(error) = current {
+            if let Some(error) = error.downcast_ref::<E>() {
+                return Some(error);
+            }
+            current = error.source();
+        }
+
+        None
+    }
+}
+
+impl ValidationErrorExt for dyn Error + Send + Sync + 'static {
+    fn downcast_validation<E: Error + 'static>(&self) -> Option<&E> {
+        <dyn Error>::downcast_validation(self)
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{Failure, Valid, Validate};
+
+/// A type which can fix minor problems in itself, rather than being rejected
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// [`RepairValidate::repair`] fixes what it can (e.g. clamping or truncating), and records each
+/// change it made. [`RepairValidate::validate_repaired`] then validates the repaired value, so the
+/// result is still guaranteed to be valid, and problems which can't be repaired are still errors:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Volume(i32);
+///
+/// impl Validate for Volume {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0..=100 => Ok(()),
+///       _ => Err("volume out of range"),
+///     }
+///   }
+/// }
+///
+/// impl RepairValidate for Volume {
+///   fn repair(&mut self, _ctx: &Self::Context<'_>) -> RepairOutcome {
+///     let mut outcome = RepairOutcome::new();
+///     let clamped = self.0.clamp(0, 100);
+///     if clamped != self.0 {
+///       outcome.record(format!("clamped {} to {clamped}", self.0));
+///       self.0 = clamped;
+///     }
+///     outcome
+///   }
+/// }
+///
+/// let (volume, outcome) = Volume(150).validate_repaired(()).unwrap();
+/// assert_eq!(volume.0, 100);
+/// assert_eq!(outcome.repairs(), ["clamped 150 to 100"]);
+///
+/// let (volume, outcome) = Volume(50).validate_repaired(()).unwrap();
+/// assert_eq!(volume.0, 50);
+/// assert!(outcome.is_clean());
+/// ```
+pub trait RepairValidate: Validate + Sized {
+    /// Fix any problems that can be fixed, returning a record of the changes made
+    ///
+    /// This doesn't need to make the value valid: anything left unrepaired is reported when
+    /// validating
+    fn repair(&mut self, ctx: &Self::Context<'_>) -> RepairOutcome;
+
+    /// Repair this value, then validate the repaired value with the given context
+    ///
+    /// If validation fails, the [`Failure`] contains the repaired value
+    fn validate_repaired(
+        mut self,
+        ctx: Self::Context<'_>,
+    ) -> Result<(Valid<Self>, RepairOutcome), Failure<Self>> {
+        let outcome = self.repair(&ctx);
+        let valid = self.validate_with(ctx)?;
+        Ok((valid, outcome))
+    }
+}
+
+/// The repairs applied by [`RepairValidate::repair`]
+///
+/// This is only available with the `alloc` feature enabled.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RepairOutcome {
+    repairs: Vec<String>,
+}
+
+impl RepairOutcome {
+    /// Create an outcome with no repairs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that a repair was applied
+    pub fn record(&mut self, repair: impl Into<String>) {
+        self.repairs.push(repair.into());
+    }
+
+    /// The repairs that were applied, in order
+    pub fn repairs(&self) -> &[String] {
+        &self.repairs
+    }
+
+    /// Whether no repairs were needed
+    pub fn is_clean(&self) -> bool {
+        self.repairs.is_empty()
+    }
+}
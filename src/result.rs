@@ -0,0 +1,81 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{Valid, Validate};
+
+/// Validate the `Ok` value of a `Result`, passing an `Err` through unchanged
+///
+/// This is useful in pipelines where an earlier stage (for example, parsing) already produces a
+/// `Result`. Both kinds of error are merged into an [`OkArmError`]:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err("port must be non-zero"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let parse = |s: &str| s.parse::<u16>().map(Port);
+///
+/// let port = validate_ok_arm(parse("8080"), ()).unwrap();
+/// assert_eq!(port.0, 8080);
+///
+/// let error = validate_ok_arm(parse("http"), ()).unwrap_err();
+/// assert!(matches!(error, OkArmError::Upstream(_)));
+///
+/// let error = validate_ok_arm(parse("0"), ()).unwrap_err();
+/// assert!(matches!(error, OkArmError::Invalid("port must be non-zero")));
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_ok_arm<T, E>(
+    result: Result<T, E>,
+    ctx: T::Context<'_>,
+) -> Result<Valid<T>, OkArmError<E, T::Error>>
+where
+    T: Validate,
+{
+    result
+        .map_err(OkArmError::Upstream)?
+        .validate_with(ctx)
+        .map_err(|failure| OkArmError::Invalid(failure.error))
+}
+
+/// The error returned by [`validate_ok_arm`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OkArmError<E, V> {
+    /// The input was already an `Err`
+    Upstream(E),
+    /// The input was `Ok`, but its value failed validation
+    Invalid(V),
+}
+
+impl<E: Display, V: Display> Display for OkArmError<E, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Upstream(error) => error.fmt(f),
+            Self::Invalid(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, V> std::error::Error for OkArmError<E, V>
+where
+    E: std::error::Error,
+    V: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Upstream(error) => error.source(),
+            Self::Invalid(error) => error.source(),
+        }
+    }
+}
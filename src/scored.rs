@@ -0,0 +1,163 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{Debug, Formatter};
+
+type Check<T, E> = Box<dyn Fn(&T) -> Result<(), E> + Send + Sync>;
+
+/// A set of weighted checks, which pass if the total weight of the failed checks stays below a
+/// threshold
+///
+/// This blends "hard" and "soft" validation. Soft checks add their weight to the score when they
+/// fail, and the value only passes if the total score is below the threshold. Critical checks
+/// fail the value outright, regardless of the score.
+///
+/// To produce a `Valid<T>`, use the `ScoredValidation` as (part of) the context of `T`, and return
+/// the [`ScoreReport`] as the error if it fails:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Comment(String);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Reason {
+///   Empty,
+///   AllCaps,
+///   TooManyLinks,
+///   TooLong,
+/// }
+///
+/// impl Validate for Comment {
+///   type Context<'a> = &'a ScoredValidation<Comment, Reason>;
+///   type Error = ScoreReport<Reason>;
+///
+///   fn is_valid(&self, scoring: Self::Context<'_>) -> Result<(), Self::Error> {
+///     scoring.check(self).map(|_| ())
+///   }
+/// }
+///
+/// let scoring = ScoredValidation::new(10)
+///   .critical(|c: &Comment| match c.0.is_empty() {
+///     true => Err(Reason::Empty),
+///     false => Ok(()),
+///   })
+///   .soft(3, |c| match c.0.chars().all(|c| !c.is_lowercase()) {
+///     true => Err(Reason::AllCaps),
+///     false => Ok(()),
+///   })
+///   .soft(8, |c| match c.0.matches("http").count() > 2 {
+///     true => Err(Reason::TooManyLinks),
+///     false => Ok(()),
+///   })
+///   .soft(4, |c| match c.0.len() > 20 {
+///     true => Err(Reason::TooLong),
+///     false => Ok(()),
+///   });
+///
+/// // two soft checks fail, but the score (3 + 4) is below the threshold
+/// let comment = Comment("THIS IS A VERY LONG COMMENT".to_string());
+/// let report = scoring.check(&comment).unwrap();
+/// assert_eq!(report.score, 7);
+/// assert_eq!(report.failures.len(), 2);
+/// assert!(comment.validate_with(&scoring).is_ok());
+///
+/// // too many soft checks fail
+/// let comment = Comment("see http://a http://b http://c".to_string());
+/// let failure = comment.validate_with(&scoring).unwrap_err();
+/// assert_eq!(failure.error.score, 12);
+///
+/// // a critical check fails
+/// let failure = Comment(String::new()).validate_with(&scoring).unwrap_err();
+/// assert!(failure.error.failures[0].critical);
+/// assert_eq!(failure.error.failures[0].error, Reason::Empty);
+/// ```
+pub struct ScoredValidation<T, E> {
+    threshold: u32,
+    checks: Vec<(u32, bool, Check<T, E>)>,
+}
+
+impl<T, E> ScoredValidation<T, E> {
+    /// Create a new set of checks, which pass if the score is strictly below `threshold`
+    pub fn new(threshold: u32) -> Self {
+        Self {
+            threshold,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Add a soft check, which adds `weight` to the score if it fails
+    pub fn soft(
+        mut self,
+        weight: u32,
+        check: impl Fn(&T) -> Result<(), E> + Send + Sync + 'static,
+    ) -> Self {
+        self.checks.push((weight, false, Box::new(check)));
+        self
+    }
+
+    /// Add a critical check, which fails the value if it fails
+    pub fn critical(mut self, check: impl Fn(&T) -> Result<(), E> + Send + Sync + 'static) -> Self {
+        self.checks.push((0, true, Box::new(check)));
+        self
+    }
+
+    /// The threshold that the score must stay below
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Run every check against `value`
+    ///
+    /// This returns `Ok` if no critical checks failed and the score is below the threshold, and
+    /// `Err` otherwise. Either way, the report contains every failed check.
+    pub fn check(&self, value: &T) -> Result<ScoreReport<E>, ScoreReport<E>> {
+        let mut report = ScoreReport {
+            score: 0,
+            failures: Vec::new(),
+        };
+
+        for (weight, critical, check) in &self.checks {
+            if let Err(error) = check(value) {
+                report.score = report.score.saturating_add(*weight);
+                report.failures.push(ScoredFailure {
+                    weight: *weight,
+                    critical: *critical,
+                    error,
+                });
+            }
+        }
+
+        let critical_failed = report.failures.iter().any(|failure| failure.critical);
+        match !critical_failed && report.score < self.threshold {
+            true => Ok(report),
+            false => Err(report),
+        }
+    }
+}
+
+impl<T, E> Debug for ScoredValidation<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScoredValidation")
+            .field("threshold", &self.threshold)
+            .field("checks", &self.checks.len())
+            .finish()
+    }
+}
+
+/// The result of running a [`ScoredValidation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreReport<E> {
+    /// The total weight of the soft checks which failed
+    pub score: u32,
+    /// The checks which failed, in the order they were added
+    pub failures: Vec<ScoredFailure<E>>,
+}
+
+/// A single failed check in a [`ScoreReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoredFailure<E> {
+    /// The weight of the check (always 0 for critical checks)
+    pub weight: u32,
+    /// Whether the check was critical
+    pub critical: bool,
+    /// The error that was generated
+    pub error: E,
+}
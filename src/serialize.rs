@@ -0,0 +1,56 @@
+use serde::{Serialize, Serializer};
+
+use crate::Valid;
+
+/// Serializes exactly like `T`
+///
+/// Since `Valid<T>` dereferences to `T`, `skip_serializing_if` predicates written for `T` can be
+/// used on `Valid<T>` fields directly:
+/// ```
+/// # use validity::*;
+/// # use serde::Serialize;
+/// #[derive(Debug, Serialize)]
+/// struct Tags(Vec<String>);
+///
+/// impl Tags {
+///   fn is_empty(&self) -> bool {
+///     self.0.is_empty()
+///   }
+/// }
+///
+/// impl Validate for Tags {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.iter().any(String::is_empty) {
+///       true => Err("empty tag"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Post {
+///   title: String,
+///   #[serde(skip_serializing_if = "Tags::is_empty")]
+///   tags: Valid<Tags>,
+/// }
+///
+/// let post = Post {
+///   title: "Hello".into(),
+///   tags: Tags(vec!["rust".into()]).validate().unwrap(),
+/// };
+/// assert_eq!(serde_json::to_string(&post).unwrap(), r#"{"title":"Hello","tags":["rust"]}"#);
+///
+/// let post = Post {
+///   title: "Hello".into(),
+///   tags: Tags(vec![]).validate().unwrap(),
+/// };
+/// assert_eq!(serde_json::to_string(&post).unwrap(), r#"{"title":"Hello"}"#);
+/// ```
+impl<T: Serialize> Serialize for Valid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
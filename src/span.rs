@@ -0,0 +1,123 @@
+use core::ops::Range;
+
+use crate::{Failure, Valid, Validate};
+
+/// An error, along with the span of source text that caused it
+///
+/// The span is a byte range into the source, which is what diagnostic crates such as `ariadne` and
+/// `codespan-reporting` expect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Spanned<E> {
+    /// The byte range of the offending part of the source
+    pub span: Range<usize>,
+    /// The error that was generated
+    pub error: E,
+}
+
+/// The context used by [`validate_spanned`]
+///
+/// This holds the source text that the value was parsed from, along with whatever other context the
+/// value needs. If the value borrows its tokens from the source (i.e. its `&str`s are slices of the
+/// source), [`SpanContext::span_of`] can recover the span of any token, so a `Validate` impl can
+/// attach spans to its errors without the value having to store them.
+#[derive(Debug, Clone, Copy)]
+pub struct SpanContext<'a, C> {
+    /// The source text that the value was parsed from
+    pub source: &'a str,
+    /// Any other context needed for validation
+    pub inner: C,
+}
+
+impl<'a, C> SpanContext<'a, C> {
+    /// Create a new `SpanContext`
+    pub fn new(source: &'a str, inner: C) -> Self {
+        Self { source, inner }
+    }
+
+    /// The span of `token` within the source, or `None` if `token` is not a slice of the source
+    pub fn span_of(&self, token: &str) -> Option<Range<usize>> {
+        let source_start = self.source.as_ptr() as usize;
+        let start = (token.as_ptr() as usize).checked_sub(source_start)?;
+        let end = start + token.len();
+
+        match end <= self.source.len() {
+            true => Some(start..end),
+            false => None,
+        }
+    }
+
+    /// Attach the span of `token` to `error`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `token` is not a slice of the source
+    pub fn error_at<E>(&self, token: &str, error: E) -> Spanned<E> {
+        let span = self
+            .span_of(token)
+            .expect("token is not a slice of the source");
+        Spanned { span, error }
+    }
+}
+
+/// Validate a value parsed from `source`, so that errors can point at the offending token
+///
+/// The value's context is a [`SpanContext`], which gives its `Validate` impl access to the source,
+/// and its error is a [`Spanned`]. Spans flow from the context into the error via
+/// [`SpanContext::error_at`]:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Assignment<'s> {
+///   name: &'s str,
+///   value: &'s str,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidAssignment {
+///   ReservedName,
+///   NotANumber,
+/// }
+///
+/// impl<'s> Validate for Assignment<'s> {
+///   type Context<'a> = SpanContext<'a, ()>;
+///   type Error = Spanned<InvalidAssignment>;
+///
+///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.name == "let" {
+///       return Err(ctx.error_at(self.name, InvalidAssignment::ReservedName));
+///     }
+///
+///     if self.value.parse::<i64>().is_err() {
+///       return Err(ctx.error_at(self.value, InvalidAssignment::NotANumber));
+///     }
+///
+///     Ok(())
+///   }
+/// }
+///
+/// fn parse(source: &str) -> Assignment<'_> {
+///   let (name, value) = source.split_once(" = ").unwrap();
+///   Assignment { name, value }
+/// }
+///
+/// let source = "x = 123";
+/// assert!(validate_spanned(parse(source), source, ()).is_ok());
+///
+/// let source = "x = abc";
+/// let failure = validate_spanned(parse(source), source, ()).unwrap_err();
+/// assert_eq!(failure.error.error, InvalidAssignment::NotANumber);
+/// assert_eq!(failure.error.span, 4..7);
+/// assert_eq!(&source[failure.error.span], "abc");
+///
+/// let source = "let = 1";
+/// let failure = validate_spanned(parse(source), source, ()).unwrap_err();
+/// assert_eq!(failure.error.error, InvalidAssignment::ReservedName);
+/// assert_eq!(&source[failure.error.span], "let");
+/// ```
+#[must_use = "validation may fail, so its result should be checked"]
+pub fn validate_spanned<T, C, E>(value: T, source: &str, ctx: C) -> Result<Valid<T>, Failure<T>>
+where
+    T: for<'a> Validate<Context<'a> = SpanContext<'a, C>, Error = Spanned<E>>,
+{
+    value.validate_with(SpanContext::new(source, ctx))
+}
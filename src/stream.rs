@@ -0,0 +1,386 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+use std::io::{self, BufRead, BufReader, Bytes, Read};
+
+use serde::{de::DeserializeOwned, de::Error as _};
+
+use crate::{Failure, Valid, Validate};
+
+/// Lazily deserialize and validate each element of a JSON array from a reader
+///
+/// This is only available with the `json-stream` feature enabled.
+///
+/// Only one element is held in memory at a time, so this is suitable for very large arrays (e.g.
+/// bulk imports). An element which fails validation is yielded as an error (along with its index)
+/// and the stream continues with the next element. Malformed JSON or an IO error ends the stream:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Record {
+///   id: u32,
+///   email: String,
+/// }
+///
+/// impl Validate for Record {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.email.contains('@') {
+///       true => Ok(()),
+///       false => Err("invalid email"),
+///     }
+///   }
+/// }
+///
+/// let json = r#"[
+///   { "id": 1, "email": "a@example.com" },
+///   { "id": 2, "email": "not an email" },
+///   { "id": 3, "email": "c@example.com" }
+/// ]"#;
+///
+/// let mut stream = validated_stream::<_, Record>(json.as_bytes());
+///
+/// assert_eq!(stream.next().unwrap().unwrap().id, 1);
+///
+/// let StreamError::Invalid { index, failure } = stream.next().unwrap().unwrap_err() else {
+///   panic!("expected a validation error");
+/// };
+/// assert_eq!(index, 1);
+/// assert_eq!(failure.value.id, 2);
+/// assert_eq!(failure.error, "invalid email");
+///
+/// assert_eq!(stream.next().unwrap().unwrap().id, 3);
+/// assert!(stream.next().is_none());
+/// ```
+pub fn validated_stream<R, T>(reader: R) -> ValidatedStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned + for<'a> Validate<Context<'a> = ()>,
+{
+    ValidatedStream {
+        bytes: BufReader::new(reader).bytes(),
+        pending: None,
+        index: 0,
+        started: false,
+        finished: false,
+        buffer: Vec::new(),
+        _marker: PhantomData,
+    }
+}
+
+/// The iterator returned by [`validated_stream`]
+pub struct ValidatedStream<R, T> {
+    bytes: Bytes<BufReader<R>>,
+    pending: Option<u8>,
+    index: usize,
+    started: bool,
+    finished: bool,
+    buffer: Vec<u8>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// The errors yielded by [`ValidatedStream`]
+pub enum StreamError<T: Validate> {
+    /// The reader returned an error
+    Io(io::Error),
+    /// The input was not a JSON array, or an element could not be deserialized
+    Json(serde_json::Error),
+    /// The element at `index` was deserialized, but failed validation
+    Invalid {
+        /// The index of the element in the array
+        index: usize,
+        /// The failure produced by validation
+        failure: Failure<T>,
+    },
+}
+
+impl<T> Debug for StreamError<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => f.debug_tuple("Io").field(error).finish(),
+            Self::Json(error) => f.debug_tuple("Json").field(error).finish(),
+            Self::Invalid { index, failure } => f
+                .debug_struct("Invalid")
+                .field("index", index)
+                .field("failure", failure)
+                .finish(),
+        }
+    }
+}
+
+impl<R: Read, T> ValidatedStream<R, T> {
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        match self.pending.take() {
+            Some(byte) => Ok(Some(byte)),
+            None => self.bytes.next().transpose(),
+        }
+    }
+
+    fn next_non_whitespace(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.next_byte()? {
+                Some(byte) if byte.is_ascii_whitespace() => continue,
+                other => return Ok(other),
+            }
+        }
+    }
+
+    /// Read the bytes of the next element into the buffer
+    ///
+    /// Returns `false` if the end of the array was reached instead
+    fn read_element(&mut self) -> Result<bool, StreamError<T>>
+    where
+        T: Validate,
+    {
+        let syntax = |msg| StreamError::Json(serde_json::Error::custom(msg));
+
+        if !self.started {
+            self.started = true;
+            if self.next_non_whitespace().map_err(StreamError::Io)? != Some(b'[') {
+                return Err(syntax("expected `[` at the start of the stream"));
+            }
+        }
+
+        let mut first = self.next_non_whitespace().map_err(StreamError::Io)?;
+        if self.index > 0 {
+            match first {
+                Some(b',') => first = self.next_non_whitespace().map_err(StreamError::Io)?,
+                Some(b']') => return Ok(false),
+                _ => return Err(syntax("expected `,` or `]` after array element")),
+            }
+        } else if first == Some(b']') {
+            return Ok(false);
+        }
+
+        self.buffer.clear();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut next = first;
+
+        loop {
+            let Some(byte) = next else {
+                return match depth == 0 && !in_string && !self.buffer.is_empty() {
+                    true => Ok(true),
+                    false => Err(syntax("unexpected end of stream")),
+                };
+            };
+
+            if in_string {
+                self.buffer.push(byte);
+                match (escaped, byte) {
+                    (true, _) => escaped = false,
+                    (false, b'\\') => escaped = true,
+                    (false, b'"') => {
+                        in_string = false;
+                        if depth == 0 {
+                            return Ok(true);
+                        }
+                    }
+                    _ => {}
+                }
+            } else {
+                match byte {
+                    b',' | b']' | b'}' if depth == 0 => {
+                        // the end of a scalar element, which belongs to the array
+                        self.pending = Some(byte);
+                        return Ok(true);
+                    }
+                    byte if byte.is_ascii_whitespace() && depth == 0 => return Ok(true),
+                    b'"' => {
+                        in_string = true;
+                        self.buffer.push(byte);
+                    }
+                    b'[' | b'{' => {
+                        depth += 1;
+                        self.buffer.push(byte);
+                    }
+                    b']' | b'}' => {
+                        depth -= 1;
+                        self.buffer.push(byte);
+                        if depth == 0 {
+                            return Ok(true);
+                        }
+                    }
+                    byte => self.buffer.push(byte),
+                }
+            }
+
+            next = self.next_byte().map_err(StreamError::Io)?;
+        }
+    }
+}
+
+impl<R, T> Iterator for ValidatedStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned + for<'a> Validate<Context<'a> = ()>,
+{
+    type Item = Result<Valid<T>, StreamError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.read_element() {
+            Ok(true) => {}
+            Ok(false) => {
+                self.finished = true;
+                return None;
+            }
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        let value = match serde_json::from_slice::<T>(&self.buffer) {
+            Ok(value) => value,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(StreamError::Json(error)));
+            }
+        };
+
+        Some(
+            value
+                .validate()
+                .map_err(|failure| StreamError::Invalid { index, failure }),
+        )
+    }
+}
+
+impl<R, T> Debug for ValidatedStream<R, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ValidatedStream")
+            .field("index", &self.index)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Deserialize and validate each line of a [JSON Lines](https://jsonlines.org/) input
+///
+/// This is only available with the `json-stream` feature enabled.
+///
+/// Unlike [`validated_stream`], a line which can't be parsed doesn't stop the batch. The valid
+/// values are returned along with the errors for every other line, each with its (1-based) line
+/// number. Blank lines are skipped. If the reader itself fails, the error is recorded and no more
+/// lines are read:
+/// ```
+/// # use validity::*;
+/// # use serde::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Record {
+///   id: u32,
+///   email: String,
+/// }
+///
+/// impl Validate for Record {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.email.contains('@') {
+///       true => Ok(()),
+///       false => Err("invalid email"),
+///     }
+///   }
+/// }
+///
+/// let input = r#"{ "id": 1, "email": "a@example.com" }
+/// { "id": 2, "email": "b@example.com"
+/// { "id": 3, "email": "not an email" }
+///
+/// { "id": 4, "email": "d@example.com" }
+/// "#;
+///
+/// let (valid, errors) = validate_jsonl::<_, Record>(input.as_bytes());
+///
+/// let ids: Vec<_> = valid.iter().map(|record| record.id).collect();
+/// assert_eq!(ids, [1, 4]);
+///
+/// assert_eq!(errors.len(), 2);
+/// assert!(matches!(errors[0], (2, JsonlError::Parse(_))));
+///
+/// let (3, JsonlError::Invalid(failure)) = &errors[1] else {
+///   panic!("expected a validation error on line 3");
+/// };
+/// assert_eq!(failure.value.id, 3);
+/// assert_eq!(failure.error, "invalid email");
+/// ```
+pub fn validate_jsonl<R, T>(reader: R) -> (Vec<Valid<T>>, JsonlErrors<T>)
+where
+    R: BufRead,
+    T: DeserializeOwned + for<'a> Validate<Context<'a> = ()>,
+{
+    let mut valid = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let number = index + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                errors.push((number, JsonlError::Io(error)));
+                break;
+            }
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<T>(&line) {
+            Ok(value) => match value.validate() {
+                Ok(value) => valid.push(value),
+                Err(failure) => errors.push((number, JsonlError::Invalid(failure))),
+            },
+            Err(error) => errors.push((number, JsonlError::Parse(error))),
+        }
+    }
+
+    (valid, errors)
+}
+
+/// The errors returned by [`validate_jsonl`], each with its line number
+pub type JsonlErrors<T> = Vec<(usize, JsonlError<T>)>;
+
+/// The error for a single line, returned by [`validate_jsonl`]
+///
+/// This is only available with the `json-stream` feature enabled.
+pub enum JsonlError<T: Validate> {
+    /// The reader returned an error
+    Io(io::Error),
+    /// The line could not be deserialized
+    Parse(serde_json::Error),
+    /// The line was deserialized, but failed validation
+    Invalid(Failure<T>),
+}
+
+impl<T> Debug for JsonlError<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => f.debug_tuple("Io").field(error).finish(),
+            Self::Parse(error) => f.debug_tuple("Parse").field(error).finish(),
+            Self::Invalid(failure) => f.debug_tuple("Invalid").field(failure).finish(),
+        }
+    }
+}
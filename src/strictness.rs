@@ -0,0 +1,150 @@
+use crate::{Failure, Valid, Validate};
+
+/// How strictly optional rules should be applied
+///
+/// Levels are ordered from least to most strict, so rules can be enabled "at `Standard` or
+/// above" with [`at_least`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum StrictnessLevel {
+    /// Only essential rules are applied
+    Lenient,
+    /// The usual rules are applied
+    #[default]
+    Standard,
+    /// All rules are applied
+    Strict,
+}
+
+/// A context which carries a [`StrictnessLevel`]
+pub trait HasStrictness {
+    /// The level rules should be applied at
+    fn strictness(&self) -> StrictnessLevel;
+}
+
+impl HasStrictness for StrictnessLevel {
+    fn strictness(&self) -> StrictnessLevel {
+        *self
+    }
+}
+
+/// A context made of a [`StrictnessLevel`] and an inner context
+///
+/// This is the context used by [`ValidateAtLevel::validate_at_level`].
+#[derive(Debug)]
+pub struct AtLevel<'a, C: ?Sized> {
+    /// The level rules should be applied at
+    pub level: StrictnessLevel,
+    /// The inner context
+    pub inner: &'a C,
+}
+
+impl<C: ?Sized> Clone for AtLevel<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for AtLevel<'_, C> {}
+
+impl<C: ?Sized> HasStrictness for AtLevel<'_, C> {
+    fn strictness(&self) -> StrictnessLevel {
+        self.level
+    }
+}
+
+/// Validate at a given [`StrictnessLevel`]
+///
+/// This is implemented for all types whose context is an [`AtLevel`]:
+/// ```
+/// # use validity::*;
+/// struct Tenant {
+///   blocked_domains: Vec<&'static str>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Email(String);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidEmail {
+///   MissingAt,
+///   BlockedDomain,
+///   PlusAddressing,
+/// }
+///
+/// fn no_plus_addressing(email: &Email, _tenant: &Tenant) -> Result<(), InvalidEmail> {
+///   match email.0.contains('+') {
+///     true => Err(InvalidEmail::PlusAddressing),
+///     false => Ok(()),
+///   }
+/// }
+///
+/// impl Validate for Email {
+///   type Context<'a> = AtLevel<'a, Tenant>;
+///   type Error = InvalidEmail;
+///
+///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     let Some((_, domain)) = self.0.split_once('@') else {
+///       return Err(InvalidEmail::MissingAt);
+///     };
+///
+///     if ctx.inner.blocked_domains.contains(&domain) {
+///       return Err(InvalidEmail::BlockedDomain);
+///     }
+///
+///     at_least(StrictnessLevel::Strict, no_plus_addressing).check(self, ctx.inner, &ctx)
+///   }
+/// }
+///
+/// let tenant = Tenant { blocked_domains: vec!["spam.example"] };
+/// let email = || Email("alice+news@example.com".to_string());
+///
+/// assert!(email().validate_at_level(StrictnessLevel::Lenient, &tenant).is_ok());
+/// assert!(email().validate_at_level(StrictnessLevel::Standard, &tenant).is_ok());
+///
+/// let failure = email().validate_at_level(StrictnessLevel::Strict, &tenant).unwrap_err();
+/// assert_eq!(failure.error, InvalidEmail::PlusAddressing);
+/// ```
+pub trait ValidateAtLevel<C: ?Sized>:
+    for<'a> Validate<Context<'a> = AtLevel<'a, C>> + Sized
+{
+    /// Validate with the given level and inner context
+    fn validate_at_level(
+        self,
+        level: StrictnessLevel,
+        ctx: &C,
+    ) -> Result<Valid<Self>, Failure<Self>> {
+        self.validate_with(AtLevel { level, inner: ctx })
+    }
+}
+
+impl<T, C: ?Sized> ValidateAtLevel<C> for T where T: for<'a> Validate<Context<'a> = AtLevel<'a, C>> {}
+
+/// A validator which only runs at or above a given [`StrictnessLevel`]
+///
+/// Create one with [`at_least`]. See [`ValidateAtLevel`] for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct LevelGated<V> {
+    level: StrictnessLevel,
+    validator: V,
+}
+
+/// Create a [`LevelGated`] validator which runs `validator` only at `level` or stricter
+pub fn at_least<V>(level: StrictnessLevel, validator: V) -> LevelGated<V> {
+    LevelGated { level, validator }
+}
+
+impl<V> LevelGated<V> {
+    /// Run the validator if the strictness of `level` is at least the required level, otherwise
+    /// return `Ok(())` without running it
+    pub fn check<T, C, E>(&self, value: &T, ctx: &C, level: &impl HasStrictness) -> Result<(), E>
+    where
+        T: ?Sized,
+        C: ?Sized,
+        V: Fn(&T, &C) -> Result<(), E>,
+    {
+        match level.strictness() >= self.level {
+            true => (self.validator)(value, ctx),
+            false => Ok(()),
+        }
+    }
+}
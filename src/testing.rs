@@ -0,0 +1,460 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::Validate;
+
+/// Assert that a value is valid, panicking with its error if it isn't
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// The context defaults to `()`, but can be passed as a second argument:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(()),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// assert_valid!(Port(80));
+/// assert_valid!(Port(443), ());
+///
+/// assert!(std::panic::catch_unwind(|| assert_valid!(Port(0))).is_err());
+/// ```
+#[macro_export]
+macro_rules! assert_valid {
+    ($value:expr $(,)?) => {
+        $crate::assert_valid!($value, ())
+    };
+    ($value:expr, $ctx:expr $(,)?) => {
+        match $crate::Validate::is_valid(&$value, $ctx) {
+            ::core::result::Result::Ok(()) => {}
+            ::core::result::Result::Err(error) => ::core::panic!(
+                "expected `{}` to be valid, but got error: {:?}",
+                ::core::stringify!($value),
+                error,
+            ),
+        }
+    };
+}
+
+/// Assert that a value is invalid, and that its error matches a pattern
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// The context defaults to `()`, but can be passed as a third argument:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// #[derive(Debug)]
+/// enum InvalidPort {
+///   Zero,
+///   Reserved(u16),
+/// }
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = InvalidPort;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(InvalidPort::Zero),
+///       1..=1023 => Err(InvalidPort::Reserved(self.0)),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// assert_invalid!(Port(0), InvalidPort::Zero);
+/// assert_invalid!(Port(80), InvalidPort::Reserved(80), ());
+///
+/// assert!(std::panic::catch_unwind(|| assert_invalid!(Port(8080), _)).is_err());
+/// assert!(std::panic::catch_unwind(|| assert_invalid!(Port(80), InvalidPort::Zero)).is_err());
+/// ```
+#[macro_export]
+macro_rules! assert_invalid {
+    ($value:expr, $pattern:pat, $ctx:expr $(,)?) => {
+        match $crate::Validate::is_valid(&$value, $ctx) {
+            ::core::result::Result::Err($pattern) => {}
+            ::core::result::Result::Err(error) => ::core::panic!(
+                "expected `{}` to fail with `{}`, but got error: {:?}",
+                ::core::stringify!($value),
+                ::core::stringify!($pattern),
+                error,
+            ),
+            ::core::result::Result::Ok(()) => ::core::panic!(
+                "expected `{}` to fail with `{}`, but it was valid",
+                ::core::stringify!($value),
+                ::core::stringify!($pattern),
+            ),
+        }
+    };
+    ($value:expr, $pattern:pat $(,)?) => {
+        $crate::assert_invalid!($value, $pattern, ())
+    };
+}
+
+/// Assert that a value is invalid with the given context, and that its error matches a pattern
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// This is [`assert_invalid!`] with the context before the pattern, which reads better when the
+/// context is always passed explicitly. If the error doesn't match, the panic message shows the
+/// actual error:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct PhoneNumber(String);
+///
+/// #[derive(Debug)]
+/// enum InvalidPhoneNumber {
+///   NonDigit,
+///   WrongLength,
+/// }
+///
+/// impl Validate for PhoneNumber {
+///   type Context<'a> = ();
+///   type Error = InvalidPhoneNumber;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.0.len() != 11 {
+///       return Err(InvalidPhoneNumber::WrongLength);
+///     }
+///     if self.0.chars().any(|c| !c.is_ascii_digit()) {
+///       return Err(InvalidPhoneNumber::NonDigit);
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// assert_invalid_with!(PhoneNumber("0123456789a".to_string()), (), InvalidPhoneNumber::NonDigit);
+/// assert_invalid_with!(PhoneNumber("0123".to_string()), (), InvalidPhoneNumber::WrongLength);
+///
+/// let wrong_variant = std::panic::catch_unwind(|| {
+///   assert_invalid_with!(PhoneNumber("0123".to_string()), (), InvalidPhoneNumber::NonDigit)
+/// });
+/// let message = wrong_variant.unwrap_err().downcast::<String>().unwrap();
+/// assert!(message.contains("but got error: WrongLength"));
+///
+/// let valid = std::panic::catch_unwind(|| {
+///   assert_invalid_with!(PhoneNumber("01234567890".to_string()), (), _)
+/// });
+/// assert!(valid.is_err());
+/// ```
+#[macro_export]
+macro_rules! assert_invalid_with {
+    ($value:expr, $ctx:expr, $pattern:pat $(,)?) => {
+        $crate::assert_invalid!($value, $pattern, $ctx)
+    };
+}
+
+/// Assert that a type's [`Default`] value is valid, panicking with its error if it isn't
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// This catches defaults which violate the type's own invariants, which are easy to introduce with
+/// `#[derive(Default)]`:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, Default)]
+/// struct Retries(u8);
+///
+/// impl Validate for Retries {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err("at least one attempt is needed"),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// #[derive(Debug, Default)]
+/// struct Delay(u64);
+///
+/// impl Validate for Delay {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 > 60_000 {
+///       true => Err("delay is too long"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// assert_default_valid::<Delay>();
+///
+/// let panic = std::panic::catch_unwind(assert_default_valid::<Retries>).unwrap_err();
+/// let message = panic.downcast::<String>().unwrap();
+/// assert!(message.contains("Retries::default()"));
+/// assert!(message.contains("at least one attempt is needed"));
+/// ```
+#[track_caller]
+pub fn assert_default_valid<T>()
+where
+    T: Default + for<'a> Validate<Context<'a> = ()>,
+    T::Error: Debug,
+{
+    if let Err(error) = T::default().is_valid(()) {
+        panic!(
+            "expected `{}::default()` to be valid, but got error: {error:?}",
+            core::any::type_name::<T>(),
+        );
+    }
+}
+
+/// A check that an error is the expected one, used by [`check_cases`]
+pub struct ErrorMatcher<E> {
+    description: &'static str,
+    matches: fn(&E) -> bool,
+}
+
+impl<E> ErrorMatcher<E> {
+    /// Create a matcher from a description of the expected error, and a function which checks it
+    pub const fn new(description: &'static str, matches: fn(&E) -> bool) -> Self {
+        Self {
+            description,
+            matches,
+        }
+    }
+
+    /// A matcher which accepts any error
+    pub const fn any() -> Self {
+        Self::new("any error", |_| true)
+    }
+
+    /// Returns `true` if `error` is the expected error
+    pub fn matches(&self, error: &E) -> bool {
+        (self.matches)(error)
+    }
+}
+
+impl<E> Debug for ErrorMatcher<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ErrorMatcher")
+            .field(&self.description)
+            .finish()
+    }
+}
+
+/// Validate each case, checking that it is valid if its matcher is `None`, or that its error
+/// matches if its matcher is `Some`
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// Every case is checked (rather than stopping at the first failure), and the cases which didn't
+/// behave as expected are collected into a [`CaseReport`]:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Port(u16);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum InvalidPort {
+///   Zero,
+///   Reserved,
+/// }
+///
+/// impl Validate for Port {
+///   type Context<'a> = ();
+///   type Error = InvalidPort;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0 {
+///       0 => Err(InvalidPort::Zero),
+///       1..=1023 => Err(InvalidPort::Reserved),
+///       _ => Ok(()),
+///     }
+///   }
+/// }
+///
+/// const ZERO: ErrorMatcher<InvalidPort> = ErrorMatcher::new("Zero", |e| *e == InvalidPort::Zero);
+/// const RESERVED: ErrorMatcher<InvalidPort> =
+///   ErrorMatcher::new("Reserved", |e| *e == InvalidPort::Reserved);
+///
+/// run_cases(&[
+///   (Port(8080), None),
+///   (Port(0), Some(ZERO)),
+///   (Port(80), Some(RESERVED)),
+///   (Port(1), Some(ErrorMatcher::any())),
+/// ], ());
+///
+/// let report = check_cases(&[
+///   (Port(8080), None),
+///   (Port(0), Some(RESERVED)),
+///   (Port(80), None),
+///   (Port(9000), Some(ZERO)),
+/// ], ()).unwrap_err();
+///
+/// assert_eq!(report.failed(), [1, 2, 3]);
+/// assert_eq!(report.to_string(), "\
+/// 3 of 4 cases failed:
+///   case 1 (Port(0)): expected error `Reserved`, got Zero
+///   case 2 (Port(80)): expected valid, got Reserved
+///   case 3 (Port(9000)): expected error `Zero`, but it was valid
+/// ");
+/// ```
+pub fn check_cases<T>(
+    cases: &[(T, Option<ErrorMatcher<T::Error>>)],
+    ctx: T::Context<'_>,
+) -> Result<(), CaseReport>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+    for<'a> T::Context<'a>: Clone,
+{
+    let mut failures = Vec::new();
+
+    for (index, (value, matcher)) in cases.iter().enumerate() {
+        let message = match (value.is_valid(ctx.clone()), matcher) {
+            (Ok(()), None) => continue,
+            (Err(error), Some(matcher)) if matcher.matches(&error) => continue,
+            (Err(error), None) => format!("expected valid, got {error:?}"),
+            (Err(error), Some(matcher)) => {
+                format!("expected error `{}`, got {error:?}", matcher.description)
+            }
+            (Ok(()), Some(matcher)) => {
+                format!("expected error `{}`, but it was valid", matcher.description)
+            }
+        };
+
+        failures.push((index, format!("case {index} ({value:?}): {message}")));
+    }
+
+    match failures.is_empty() {
+        true => Ok(()),
+        false => Err(CaseReport {
+            total: cases.len(),
+            failures,
+        }),
+    }
+}
+
+/// Like [`check_cases`], but panics with the report if any case fails
+///
+/// This is only available with the `test-support` feature enabled.
+#[track_caller]
+pub fn run_cases<T>(cases: &[(T, Option<ErrorMatcher<T::Error>>)], ctx: T::Context<'_>)
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+    for<'a> T::Context<'a>: Clone,
+{
+    if let Err(report) = check_cases(cases, ctx) {
+        panic!("{report}");
+    }
+}
+
+/// A report of the cases which failed in [`check_cases`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseReport {
+    total: usize,
+    failures: Vec<(usize, String)>,
+}
+
+impl CaseReport {
+    /// The indices of the cases which failed
+    pub fn failed(&self) -> Vec<usize> {
+        self.failures.iter().map(|(index, _)| *index).collect()
+    }
+}
+
+impl Display for CaseReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "{} of {} cases failed:", self.failures.len(), self.total)?;
+        for (_, message) in &self.failures {
+            writeln!(f, "  {message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate each input, and render the results as a stable, line-based string
+///
+/// This is only available with the `test-support` feature enabled.
+///
+/// Each line contains the `Debug` output of an input followed by its result, in the order the
+/// inputs were given. This is intended for snapshot testing (e.g. with `insta::assert_snapshot!`),
+/// so that any change to which inputs a validator accepts, or which errors it returns, shows up as
+/// a diff:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct PhoneNumber(&'static str);
+///
+/// #[derive(Debug)]
+/// enum InvalidPhoneNumber {
+///   NonDigit,
+///   WrongLength,
+/// }
+///
+/// impl Validate for PhoneNumber {
+///   type Context<'a> = ();
+///   type Error = InvalidPhoneNumber;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.0.len() != 11 {
+///       return Err(InvalidPhoneNumber::WrongLength);
+///     }
+///
+///     if self.0.chars().any(|c| !c.is_ascii_digit()) {
+///       return Err(InvalidPhoneNumber::NonDigit);
+///     }
+///
+///     Ok(())
+///   }
+/// }
+///
+/// let corpus = [
+///   "01234567890",
+///   "",
+///   "0123456789",
+///   "012345678901",
+///   "0123456789a",
+///   "+4477009001",
+///   "０１２３４５６７８９０",
+/// ].map(PhoneNumber);
+///
+/// assert_eq!(snapshot_cases(&corpus, ()), r#"PhoneNumber("01234567890") => Ok
+/// PhoneNumber("") => Err(WrongLength)
+/// PhoneNumber("0123456789") => Err(WrongLength)
+/// PhoneNumber("012345678901") => Err(WrongLength)
+/// PhoneNumber("0123456789a") => Err(NonDigit)
+/// PhoneNumber("+4477009001") => Err(NonDigit)
+/// PhoneNumber("０１２３４５６７８９０") => Err(WrongLength)
+/// "#);
+/// ```
+pub fn snapshot_cases<T>(inputs: &[T], ctx: T::Context<'_>) -> String
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+    for<'a> T::Context<'a>: Clone,
+{
+    let mut output = String::new();
+
+    for input in inputs {
+        let line = match input.is_valid(ctx.clone()) {
+            Ok(()) => format!("{input:?} => Ok\n"),
+            Err(error) => format!("{input:?} => Err({error:?})\n"),
+        };
+        output.push_str(&line);
+    }
+
+    output
+}
@@ -0,0 +1,69 @@
+use crate::Valid;
+
+/// Searching a string for a valid string
+///
+/// `str::contains` and friends accept any [`Pattern`](core::str::pattern::Pattern), but that
+/// trait is unstable, so `Valid<T>` can't implement it. This trait provides the common searches,
+/// taking the needle as a `&Valid<T>` for any `T: AsRef<str>` (including `String`):
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Keyword(String);
+///
+/// impl AsRef<str> for Keyword {
+///   fn as_ref(&self) -> &str {
+///     &self.0
+///   }
+/// }
+///
+/// impl Validate for Keyword {
+///   type Context<'a> = ();
+///   type Error = ();
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match !self.0.is_empty() && self.0.chars().all(char::is_alphanumeric) {
+///       true => Ok(()),
+///       false => Err(()),
+///     }
+///   }
+/// }
+///
+/// let needle = Keyword("rust".to_string()).validate().unwrap();
+/// let haystack = "trust in rust";
+///
+/// assert!(haystack.contains_valid(&needle));
+/// assert!(!haystack.starts_with_valid(&needle));
+/// assert!(haystack.ends_with_valid(&needle));
+/// assert_eq!(haystack.find_valid(&needle), Some(1));
+/// ```
+pub trait ValidStrExt {
+    /// Returns `true` if `needle` is a substring of this string
+    fn contains_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool;
+
+    /// Returns `true` if this string starts with `needle`
+    fn starts_with_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool;
+
+    /// Returns `true` if this string ends with `needle`
+    fn ends_with_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool;
+
+    /// The byte index of the first occurrence of `needle`, if any
+    fn find_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> Option<usize>;
+}
+
+impl ValidStrExt for str {
+    fn contains_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool {
+        self.contains(needle.0.as_ref())
+    }
+
+    fn starts_with_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool {
+        self.starts_with(needle.0.as_ref())
+    }
+
+    fn ends_with_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> bool {
+        self.ends_with(needle.0.as_ref())
+    }
+
+    fn find_valid<T: AsRef<str>>(&self, needle: &Valid<T>) -> Option<usize> {
+        self.find(needle.0.as_ref())
+    }
+}
@@ -0,0 +1,119 @@
+/// A typestate marker for a required field of a context builder which hasn't been set yet
+///
+/// Together with [`Provided`], this allows a builder for a context with several required parts to
+/// only have a `build` method once every part is set, so an incomplete context is a compile error
+/// rather than a runtime surprise. Each required field is a type parameter which starts as
+/// `Missing`, and each setter changes it to `Provided<T>`:
+/// ```
+/// # use validity::*;
+/// # use std::collections::HashSet;
+/// struct Db {
+///   taken: HashSet<String>,
+/// }
+///
+/// struct Flags {
+///   allow_short_names: bool,
+/// }
+///
+/// struct Context<'a> {
+///   db: &'a Db,
+///   flags: &'a Flags,
+/// }
+///
+/// struct ContextBuilder<D, F> {
+///   db: D,
+///   flags: F,
+/// }
+///
+/// impl<'a> Context<'a> {
+///   fn builder() -> ContextBuilder<Missing, Missing> {
+///     ContextBuilder { db: Missing, flags: Missing }
+///   }
+/// }
+///
+/// impl<D, F> ContextBuilder<D, F> {
+///   fn db(self, db: &Db) -> ContextBuilder<Provided<&Db>, F> {
+///     ContextBuilder { db: Provided(db), flags: self.flags }
+///   }
+///
+///   fn flags(self, flags: &Flags) -> ContextBuilder<D, Provided<&Flags>> {
+///     ContextBuilder { db: self.db, flags: Provided(flags) }
+///   }
+/// }
+///
+/// impl<'a> ContextBuilder<Provided<&'a Db>, Provided<&'a Flags>> {
+///   fn build(self) -> Context<'a> {
+///     Context { db: self.db.into_inner(), flags: self.flags.into_inner() }
+///   }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = Context<'a>;
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     if self.0.len() < 3 && !ctx.flags.allow_short_names {
+///       return Err("username is too short");
+///     }
+///     match ctx.db.taken.contains(&self.0) {
+///       true => Err("username is taken"),
+///       false => Ok(()),
+///     }
+///   }
+/// }
+///
+/// let db = Db { taken: HashSet::from(["root".to_string()]) };
+/// let flags = Flags { allow_short_names: false };
+///
+/// // setters can be called in any order
+/// let ctx = || Context::builder().flags(&flags).db(&db).build();
+/// assert!(Username("alice".to_string()).validate_with(ctx()).is_ok());
+/// assert!(Username("al".to_string()).validate_with(ctx()).is_err());
+/// assert!(Username("root".to_string()).validate_with(ctx()).is_err());
+/// ```
+/// Calling `build` before every field is set fails to compile:
+/// ```compile_fail
+/// # use validity::*;
+/// struct Context<'a> {
+///   name: &'a str,
+///   limit: usize,
+/// }
+///
+/// struct ContextBuilder<N, L> {
+///   name: N,
+///   limit: L,
+/// }
+///
+/// impl<N, L> ContextBuilder<N, L> {
+///   fn name(self, name: &str) -> ContextBuilder<Provided<&str>, L> {
+///     ContextBuilder { name: Provided(name), limit: self.limit }
+///   }
+/// }
+///
+/// impl<'a> ContextBuilder<Provided<&'a str>, Provided<usize>> {
+///   fn build(self) -> Context<'a> {
+///     Context { name: self.name.into_inner(), limit: self.limit.into_inner() }
+///   }
+/// }
+///
+/// let builder = ContextBuilder { name: Missing, limit: Missing };
+/// let ctx = builder.name("orders").build();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Missing;
+
+/// A typestate marker for a required field of a context builder which has been set
+///
+/// See [`Missing`] for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Provided<T>(pub T);
+
+impl<T> Provided<T> {
+    /// Consume self and return the provided value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,168 @@
+use core::fmt::{Display, Formatter};
+
+use alloc::{string::String, vec::Vec};
+use url::Url;
+
+use crate::Validator;
+
+/// A [`Validator`] which checks that a string is a URL, optionally restricting its scheme and
+/// host
+///
+/// This is only available with the `url` feature enabled.
+///
+/// The string is parsed with [`Url::parse`]. With no restrictions, any URL is accepted. Schemes
+/// and hosts are compared case-insensitively, since the parser normalizes them to lowercase:
+/// ```
+/// # use validity::*;
+/// let validator = UrlValidator::new().schemes(["https"]).hosts(["example.com"]);
+///
+/// assert_eq!(validator.check("https://example.com/docs?page=2"), Ok(()));
+/// assert_eq!(validator.check("HTTPS://EXAMPLE.COM"), Ok(()));
+///
+/// let error = validator.check("http://example.com").unwrap_err();
+/// assert_eq!(error, UrlError::DisallowedScheme("http".to_string()));
+/// assert_eq!(error.to_string(), "URL scheme `http` is not allowed");
+///
+/// let error = validator.check("https://evil.example").unwrap_err();
+/// assert_eq!(error.to_string(), "URL host `evil.example` is not allowed");
+///
+/// let error = validator.check("mailto:someone@example.com").unwrap_err();
+/// assert_eq!(error, UrlError::DisallowedScheme("mailto".to_string()));
+///
+/// let error = validator.check("not a url").unwrap_err();
+/// assert!(matches!(error, UrlError::Parse(_)));
+/// assert_eq!(error.to_string(), "invalid URL: relative URL without a base");
+/// ```
+/// It is typically used from the `Validate` impl of a newtype:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug)]
+/// struct Homepage(String);
+///
+/// impl Validate for Homepage {
+///   type Context<'a> = ();
+///   type Error = UrlError;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     UrlValidator::new().schemes(["http", "https"]).check(&self.0)
+///   }
+/// }
+///
+/// assert!(Homepage("https://example.com".to_string()).validate().is_ok());
+/// assert!(Homepage("ftp://example.com".to_string()).validate().is_err());
+/// ```
+/// Like the other validators in this crate, it can be combined with `&` and `|`:
+/// ```
+/// # use validity::*;
+/// let short_https = UrlValidator::new().schemes(["https"]) & rule(|s: &str| match s.len() <= 30 {
+///   true => Ok(()),
+///   false => Err("must be at most 30 characters"),
+/// });
+///
+/// assert_eq!(short_https.check("https://example.com"), Ok(()));
+///
+/// let error = short_https.check("http://example.com").unwrap_err();
+/// assert_eq!(error, AndError::Left(UrlError::DisallowedScheme("http".to_string())));
+///
+/// let error = short_https.check("https://example.com/a/very/long/path").unwrap_err();
+/// assert_eq!(error.to_string(), "must be at most 30 characters");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlValidator {
+    schemes: Option<Vec<String>>,
+    hosts: Option<Vec<String>>,
+}
+
+impl UrlValidator {
+    /// Create a validator which accepts any URL
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only accept URLs with one of these schemes
+    pub fn schemes<S: Into<String>>(mut self, schemes: impl IntoIterator<Item = S>) -> Self {
+        self.schemes = Some(lowercase(schemes));
+        self
+    }
+
+    /// Only accept URLs with one of these hosts
+    ///
+    /// URLs without a host (such as `mailto:` URLs) are rejected.
+    pub fn hosts<S: Into<String>>(mut self, hosts: impl IntoIterator<Item = S>) -> Self {
+        self.hosts = Some(lowercase(hosts));
+        self
+    }
+}
+
+fn lowercase<S: Into<String>>(items: impl IntoIterator<Item = S>) -> Vec<String> {
+    items
+        .into_iter()
+        .map(|item| item.into().to_ascii_lowercase())
+        .collect()
+}
+
+impl Validator<str> for UrlValidator {
+    type Error = UrlError;
+
+    fn check(&self, value: &str) -> Result<(), Self::Error> {
+        let url = Url::parse(value).map_err(UrlError::Parse)?;
+
+        if let Some(schemes) = &self.schemes {
+            if !schemes.iter().any(|scheme| scheme == url.scheme()) {
+                return Err(UrlError::DisallowedScheme(url.scheme().into()));
+            }
+        }
+
+        if let Some(hosts) = &self.hosts {
+            let host = url.host_str().ok_or(UrlError::MissingHost)?;
+            if !hosts.iter().any(|allowed| allowed == host) {
+                return Err(UrlError::DisallowedHost(host.into()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validator<String> for UrlValidator {
+    type Error = UrlError;
+
+    fn check(&self, value: &String) -> Result<(), Self::Error> {
+        <Self as Validator<str>>::check(self, value)
+    }
+}
+
+/// The error returned by [`UrlValidator`]
+///
+/// This is only available with the `url` feature enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The string couldn't be parsed as a URL
+    Parse(url::ParseError),
+    /// The URL's scheme wasn't one of the allowed schemes
+    DisallowedScheme(String),
+    /// Hosts were restricted, but the URL has no host
+    MissingHost,
+    /// The URL's host wasn't one of the allowed hosts
+    DisallowedHost(String),
+}
+
+impl Display for UrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "invalid URL: {error}"),
+            Self::DisallowedScheme(scheme) => write!(f, "URL scheme `{scheme}` is not allowed"),
+            Self::MissingHost => write!(f, "URL has no host"),
+            Self::DisallowedHost(host) => write!(f, "URL host `{host}` is not allowed"),
+        }
+    }
+}
+
+impl std::error::Error for UrlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{Failure, Valid, Validate};
+
+/// A type whose validation rules are versioned
+///
+/// When the rules in a [`Validate`] impl change, data which was validated under the old rules may
+/// no longer be valid. Bumping [`VERSION`](VersionedValidate::VERSION) whenever the rules change
+/// allows persisted values (see [`Versioned`]) to record which rules they were validated under.
+pub trait VersionedValidate: Validate {
+    /// The version of the current validation rules
+    const VERSION: u32;
+}
+
+/// A value, tagged with the version of the rules it was last validated under
+///
+/// This is intended for persisting valid values. A `Versioned<T>` doesn't prove that the value is
+/// valid (it may have been loaded from storage), so it must be re-validated with
+/// [`Versioned::revalidate`] to get a `Valid<T>` back:
+/// ```
+/// # use validity::*;
+/// #[derive(Debug, Clone)]
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     // version 1 allowed up to 10 characters
+///     match self.0.len() <= 5 {
+///       true => Ok(()),
+///       false => Err("username too long"),
+///     }
+///   }
+/// }
+///
+/// impl VersionedValidate for Username {
+///   const VERSION: u32 = 2;
+/// }
+///
+/// let stored = Username("alice".to_string()).validate().unwrap().versioned();
+/// assert_eq!(stored.version, 2);
+/// assert!(stored.is_current());
+///
+/// // loaded from a database, written under version 1 of the rules
+/// let stored = Versioned { version: 1, value: Username("alexander".to_string()) };
+/// assert!(!stored.is_current());
+///
+/// let failure = stored.revalidate(()).unwrap_err();
+/// assert_eq!(failure.version, 1);
+/// assert_eq!(failure.failure.error, "username too long");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Versioned<T> {
+    /// The version of the rules the value was validated under
+    pub version: u32,
+    /// The value
+    pub value: T,
+}
+
+impl<T: VersionedValidate> Valid<T> {
+    /// Tag this value with the current version of its validation rules
+    pub fn versioned(self) -> Versioned<T> {
+        Versioned {
+            version: T::VERSION,
+            value: self.0,
+        }
+    }
+}
+
+impl<T: VersionedValidate> Versioned<T> {
+    /// Returns `true` if the value was validated under the current version of the rules
+    pub fn is_current(&self) -> bool {
+        self.version == T::VERSION
+    }
+
+    /// Validate the value against the current version of the rules
+    ///
+    /// If validation fails, the error includes the version that the value was previously
+    /// validated under
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn revalidate(self, ctx: T::Context<'_>) -> Result<Valid<T>, VersionedFailure<T>> {
+        let version = self.version;
+        self.value
+            .validate_with(ctx)
+            .map_err(|failure| VersionedFailure { version, failure })
+    }
+}
+
+/// The error returned by [`Versioned::revalidate`]
+pub struct VersionedFailure<T: Validate> {
+    /// The version of the rules the value was previously validated under
+    pub version: u32,
+    /// The failure produced by the current rules
+    pub failure: Failure<T>,
+}
+
+impl<T> Debug for VersionedFailure<T>
+where
+    T: Validate + Debug,
+    T::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VersionedFailure")
+            .field("version", &self.version)
+            .field("failure", &self.failure)
+            .finish()
+    }
+}
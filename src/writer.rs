@@ -0,0 +1,89 @@
+use alloc::string::String;
+use core::{fmt::Write, marker::PhantomData};
+
+use crate::{Failure, Valid, Validate};
+
+/// A [`core::fmt::Write`] implementation which validates the finished string
+///
+/// This is only available with the `alloc` feature enabled.
+///
+/// Text is accumulated into a `String` (e.g. with `write!`), and [`ValidatingWriter::finish`]
+/// converts it into a `T` and validates it:
+/// ```
+/// # use validity::*;
+/// # use std::fmt::Write;
+/// #[derive(Debug)]
+/// struct Slug(String);
+///
+/// impl From<String> for Slug {
+///   fn from(s: String) -> Self {
+///     Slug(s)
+///   }
+/// }
+///
+/// impl Validate for Slug {
+///   type Context<'a> = ();
+///   type Error = &'static str;
+///
+///   fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+///     match self.0.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+///       true => Ok(()),
+///       false => Err("invalid slug"),
+///     }
+///   }
+/// }
+///
+/// let mut writer = ValidatingWriter::<Slug>::new();
+/// write!(writer, "{}-{}", "post", 42).unwrap();
+/// writer.write_str("-draft").unwrap();
+/// let slug = writer.finish(()).unwrap();
+/// assert_eq!(slug.0, "post-42-draft");
+///
+/// let mut writer = ValidatingWriter::<Slug>::new();
+/// write!(writer, "Hello {}", "World").unwrap();
+/// let failure = writer.finish(()).unwrap_err();
+/// assert_eq!(failure.value.0, "Hello World");
+/// assert_eq!(failure.error, "invalid slug");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidatingWriter<T> {
+    buffer: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ValidatingWriter<T> {
+    /// Create a writer with an empty buffer
+    pub fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The text written so far
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Convert the text written so far into a `T`, and validate it with the given context
+    #[must_use = "validation may fail, so its result should be checked"]
+    pub fn finish(self, ctx: T::Context<'_>) -> Result<Valid<T>, Failure<T>>
+    where
+        T: From<String> + Validate,
+    {
+        T::from(self.buffer).validate_with(ctx)
+    }
+}
+
+impl<T> Default for ValidatingWriter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Write for ValidatingWriter<T> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.buffer.push_str(s);
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "actix")]
+
+use actix_web::{
+    body::to_bytes,
+    http::StatusCode,
+    test::{call_service, init_service, TestRequest},
+    web, App,
+};
+use serde::Deserialize;
+use validity::{RedactableError, Redacted, Validate, ValidatedForm, ValidatedJson};
+
+#[derive(Debug, Deserialize)]
+struct Signup {
+    username: String,
+}
+
+impl Validate for Signup {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.username.is_empty() {
+            true => Err("username must not be empty"),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Handle(String);
+
+struct ReservedHandle(String);
+
+impl RedactableError for ReservedHandle {
+    fn public_message(&self) -> String {
+        "handle is not available".to_string()
+    }
+
+    fn internal_message(&self) -> String {
+        format!("`{}` is reserved", self.0)
+    }
+}
+
+impl Validate for Handle {
+    type Context<'a> = ();
+    type Error = Redacted<ReservedHandle>;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.0.as_str() {
+            "admin" => Err(Redacted(ReservedHandle(self.0.clone()))),
+            _ => Ok(()),
+        }
+    }
+}
+
+async fn handle(ValidatedJson(handle): ValidatedJson<Handle>) -> String {
+    handle.into_inner().0
+}
+
+async fn json(ValidatedJson(signup): ValidatedJson<Signup>) -> String {
+    signup.into_inner().username
+}
+
+async fn form(ValidatedForm(signup): ValidatedForm<Signup>) -> String {
+    signup.into_inner().username
+}
+
+fn app() -> App<
+    impl actix_web::dev::ServiceFactory<
+        actix_web::dev::ServiceRequest,
+        Config = (),
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+        InitError = (),
+    >,
+> {
+    App::new()
+        .route("/json", web::post().to(json))
+        .route("/form", web::post().to(form))
+        .route("/handle", web::post().to(handle))
+}
+
+#[actix_web::test]
+async fn json_valid() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({ "username": "alice" }))
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(to_bytes(response.into_body()).await.unwrap(), "alice");
+}
+
+#[actix_web::test]
+async fn json_invalid() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({ "username": "" }))
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        to_bytes(response.into_body()).await.unwrap(),
+        "username must not be empty"
+    );
+}
+
+#[actix_web::test]
+async fn json_malformed() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/json")
+        .insert_header(("content-type", "application/json"))
+        .set_payload("{ not json")
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn form_valid() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/form")
+        .set_form([("username", "alice")])
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(to_bytes(response.into_body()).await.unwrap(), "alice");
+}
+
+#[actix_web::test]
+async fn form_invalid() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/form")
+        .set_form([("username", "")])
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        to_bytes(response.into_body()).await.unwrap(),
+        "username must not be empty"
+    );
+}
+
+#[actix_web::test]
+async fn redacted_error() {
+    let app = init_service(app()).await;
+    let request = TestRequest::post()
+        .uri("/handle")
+        .set_json("admin")
+        .to_request();
+
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // the error (as logged) has the internal message
+    let error = response.response().error().unwrap();
+    assert!(format!("{error:?}").contains("`admin` is reserved"));
+
+    // the client only sees the public message
+    assert_eq!(
+        to_bytes(response.into_body()).await.unwrap(),
+        "handle is not available"
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "debug-assert-valid", debug_assertions))]
+
+use std::{
+    cell::Cell,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use validity::Validate;
+
+#[derive(Debug)]
+struct Even(Cell<u32>);
+
+impl Validate for Even {
+    type Context<'a> = ();
+    type Error = ();
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.0.get() % 2 {
+            0 => Ok(()),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AtLeast(u32);
+
+impl Validate for AtLeast {
+    type Context<'a> = u32;
+    type Error = ();
+
+    fn is_valid(&self, min: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.0 >= min {
+            true => Ok(()),
+            false => Err(()),
+        }
+    }
+}
+
+#[test]
+fn debug_check_panics_when_no_longer_valid() {
+    let even = Even(Cell::new(2)).validate().unwrap();
+    assert_eq!(even.debug_check().0.get(), 2);
+
+    even.0.set(3);
+    let result = catch_unwind(AssertUnwindSafe(|| even.debug_check().0.get()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn deref_is_available_for_any_context() {
+    let value = AtLeast(5).validate_with(3).unwrap();
+    assert_eq!(value.0, 5);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+use validity::{Valid, Validate};
+
+#[derive(Debug, Deserialize)]
+struct Config {
+    host: String,
+    port: u16,
+    limits: Limits,
+}
+
+#[derive(Debug, Deserialize)]
+struct Limits {
+    max_connections: u32,
+}
+
+impl Validate for Config {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        if self.port == 0 {
+            return Err("port must not be zero");
+        }
+
+        if self.limits.max_connections == 0 {
+            return Err("max_connections must not be zero");
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn toml_valid() {
+    let config: Valid<Config> = toml::from_str(include_str!("fixtures/config.toml")).unwrap();
+    let config = config.into_inner();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.limits.max_connections, 100);
+}
+
+#[test]
+fn toml_invalid() {
+    let error =
+        toml::from_str::<Valid<Config>>(include_str!("fixtures/invalid_config.toml")).unwrap_err();
+
+    assert!(error.to_string().contains("port must not be zero"));
+}
+
+#[test]
+fn yaml_valid() {
+    let config: Valid<Config> = serde_yaml::from_str(include_str!("fixtures/config.yaml")).unwrap();
+    let config = config.into_inner();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.limits.max_connections, 100);
+}
+
+#[test]
+fn yaml_invalid() {
+    let error = serde_yaml::from_str::<Valid<Config>>(include_str!("fixtures/invalid_config.yaml"))
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("max_connections must not be zero"));
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+use validity::{Valid, Validate};
+
+#[derive(Debug, Deserialize)]
+struct Circle {
+    radius: f64,
+}
+
+impl Validate for Circle {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self.radius > 0.0 {
+            true => Ok(()),
+            false => Err("radius must be positive"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum Internal {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+impl Validate for Internal {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self {
+            Self::Circle { radius } if *radius <= 0.0 => Err("radius must be positive"),
+            Self::Square { side } if *side <= 0.0 => Err("side must be positive"),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", content = "shape")]
+enum Adjacent {
+    Circle(Circle),
+    Square { side: f64 },
+}
+
+impl Validate for Adjacent {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        match self {
+            Self::Circle(circle) => circle.is_valid(ctx),
+            Self::Square { side } if *side <= 0.0 => Err("side must be positive"),
+            Self::Square { .. } => Ok(()),
+        }
+    }
+}
+
+/// An internally-tagged enum whose payload is already validated, so serde has to buffer the
+/// content before deserializing the `Valid<Circle>`
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum InternalNested {
+    Circle(Valid<Circle>),
+    Empty,
+}
+
+#[test]
+fn internally_tagged() {
+    let shape: Valid<Internal> =
+        serde_json::from_str(r#"{ "type": "Square", "side": 2.0 }"#).unwrap();
+    assert!(matches!(shape.into_inner(), Internal::Square { side } if side == 2.0));
+
+    let error = serde_json::from_str::<Valid<Internal>>(r#"{ "type": "Circle", "radius": -1.0 }"#)
+        .unwrap_err();
+    assert!(error.to_string().contains("radius must be positive"));
+}
+
+#[test]
+fn adjacently_tagged() {
+    let shape: Valid<Adjacent> =
+        serde_json::from_str(r#"{ "type": "Circle", "shape": { "radius": 1.5 } }"#).unwrap();
+    assert!(matches!(shape.into_inner(), Adjacent::Circle(Circle { radius }) if radius == 1.5));
+
+    let error = serde_json::from_str::<Valid<Adjacent>>(
+        r#"{ "shape": { "radius": 0.0 }, "type": "Circle" }"#,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("radius must be positive"));
+}
+
+#[test]
+fn internally_tagged_with_valid_payload() {
+    let shape: InternalNested =
+        serde_json::from_str(r#"{ "type": "Circle", "radius": 3.0 }"#).unwrap();
+    assert!(matches!(shape, InternalNested::Circle(circle) if circle.radius == 3.0));
+
+    let shape: InternalNested = serde_json::from_str(r#"{ "type": "Empty" }"#).unwrap();
+    assert!(matches!(shape, InternalNested::Empty));
+
+    let error = serde_json::from_str::<InternalNested>(r#"{ "radius": -3.0, "type": "Circle" }"#)
+        .unwrap_err();
+    assert!(error.to_string().contains("radius must be positive"));
+}
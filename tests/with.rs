@@ -0,0 +1,152 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Deserializer, Serialize};
+use validity::{Valid, Validate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ValidDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Validate for ValidDate {
+    type Context<'a> = ();
+    type Error = &'static str;
+
+    fn is_valid(&self, _ctx: Self::Context<'_>) -> Result<(), Self::Error> {
+        let leap = self.year.is_multiple_of(4)
+            && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400));
+        let days = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return Err("month out of range"),
+        };
+
+        match self.day {
+            1.. if self.day <= days => Ok(()),
+            _ => Err("day out of range"),
+        }
+    }
+}
+
+/// A custom `YYYY-MM-DD` format, which only checks the shape of the string
+mod date_format {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::ValidDate;
+
+    pub fn serialize<S: Serializer>(date: &ValidDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let formatted = format!("{:04}-{:02}-{:02}", date.year, date.month, date.day);
+        serializer.serialize_str(&formatted)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ValidDate, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let mut parts = s.splitn(3, '-');
+        let mut next = || {
+            parts
+                .next()
+                .ok_or_else(|| D::Error::custom("expected `YYYY-MM-DD`"))
+        };
+
+        Ok(ValidDate {
+            year: next()?.parse().map_err(D::Error::custom)?,
+            month: next()?.parse().map_err(D::Error::custom)?,
+            day: next()?.parse().map_err(D::Error::custom)?,
+        })
+    }
+}
+
+/// Adapts `date_format` for use with `#[serde(with = "valid_date_format")]` on `Valid` fields
+mod valid_date_format {
+    use serde::{Deserializer, Serializer};
+    use validity::Valid;
+
+    use super::{date_format, ValidDate};
+
+    pub fn serialize<S: Serializer>(
+        date: &Valid<ValidDate>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        date_format::serialize(date, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Valid<ValidDate>, D::Error> {
+        validity::deserialize_valid_with(deserializer, date_format::deserialize)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Event {
+    name: String,
+    #[serde(with = "valid_date_format")]
+    date: Valid<ValidDate>,
+}
+
+fn deserialize_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Valid<ValidDate>, D::Error> {
+    valid_date_format::deserialize(deserializer)
+}
+
+#[test]
+fn custom_format_valid() {
+    let event: Event =
+        serde_json::from_str(r#"{ "name": "launch", "date": "2024-02-29" }"#).unwrap();
+    let date = event.date.into_inner();
+
+    assert_eq!(
+        date,
+        ValidDate {
+            year: 2024,
+            month: 2,
+            day: 29
+        }
+    );
+}
+
+#[test]
+fn custom_format_round_trip() {
+    let json = r#"{"name":"launch","date":"2024-02-29"}"#;
+    let event: Event = serde_json::from_str(json).unwrap();
+
+    assert_eq!(serde_json::to_string(&event).unwrap(), json);
+}
+
+#[test]
+fn custom_format_rejects_malformed() {
+    let error =
+        serde_json::from_str::<Event>(r#"{ "name": "launch", "date": "2024/02/29" }"#).unwrap_err();
+
+    assert!(error.to_string().contains("invalid digit"));
+}
+
+#[test]
+fn out_of_range_date_rejected_after_parsing() {
+    let error =
+        serde_json::from_str::<Event>(r#"{ "name": "launch", "date": "2023-02-29" }"#).unwrap_err();
+    assert!(error.to_string().contains("day out of range"));
+
+    let error =
+        serde_json::from_str::<Event>(r#"{ "name": "launch", "date": "2024-13-01" }"#).unwrap_err();
+    assert!(error.to_string().contains("month out of range"));
+}
+
+#[test]
+fn deserialize_with_function() {
+    #[derive(Debug, Deserialize)]
+    struct Deadline {
+        #[serde(deserialize_with = "deserialize_date")]
+        due: Valid<ValidDate>,
+    }
+
+    let deadline: Deadline = serde_json::from_str(r#"{ "due": "2025-12-31" }"#).unwrap();
+    assert_eq!(deadline.due.into_inner().day, 31);
+
+    assert!(serde_json::from_str::<Deadline>(r#"{ "due": "2025-12-32" }"#).is_err());
+}